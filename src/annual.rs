@@ -0,0 +1,222 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeDelta, Utc};
+
+use crate::event::SolarEvent;
+use crate::julian::datetime_to_julian;
+use crate::model::SolarModel;
+use crate::solar_equation::SolarDay;
+
+/// Extremes of sunrise, sunset and day length over a year, see [`AnnualSummary::new`].
+///
+/// The earliest and latest sunrise and sunset compare the time of day in local mean time, so they
+/// do not depend on the time zone or the daylight saving time. When several days share the same
+/// extreme, such as during the midnight sun or the polar night, the first of them is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AnnualSummary {
+    /// Sunrise with the earliest time of day, or `None` if the sun never rises.
+    pub earliest_sunrise: Option<DateTime<Utc>>,
+    /// Sunrise with the latest time of day, or `None` if the sun never rises.
+    pub latest_sunrise: Option<DateTime<Utc>>,
+    /// Sunset with the earliest time of day, or `None` if the sun never sets.
+    pub earliest_sunset: Option<DateTime<Utc>>,
+    /// Sunset with the latest time of day, or `None` if the sun never sets.
+    pub latest_sunset: Option<DateTime<Utc>>,
+    /// Date and length of the longest day.
+    pub longest_day: (NaiveDate, TimeDelta),
+    /// Date and length of the shortest day.
+    pub shortest_day: (NaiveDate, TimeDelta),
+    /// Date at which the day length grows the most since the previous day, and by how much.
+    pub fastest_lengthening: (NaiveDate, TimeDelta),
+    /// Date at which the day length shrinks the most since the previous day, and by how much
+    /// (which is negative).
+    pub fastest_shortening: (NaiveDate, TimeDelta),
+    /// Total time between sunrise and sunset over the year.
+    pub total_daylight: TimeDelta,
+}
+
+impl AnnualSummary {
+    /// Compute the extremes of a given year, over the solar days built by `solar_day` for each of
+    /// its dates.
+    ///
+    /// Returns `None` if the year is out of the range of supported dates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Datelike;
+    /// use sunrise::{AnnualSummary, Coordinates, SolarDay};
+    ///
+    /// let coord = Coordinates::new(59.9139, 10.7522).unwrap();
+    /// let summary = AnnualSummary::new(|date| SolarDay::new(coord, date), 2024).unwrap();
+    ///
+    /// let (longest, _) = summary.longest_day;
+    /// assert_eq!(longest.month(), 6);
+    /// ```
+    pub fn new<M: SolarModel>(
+        solar_day: impl Fn(NaiveDate) -> SolarDay<M>,
+        year: i32,
+    ) -> Option<Self> {
+        let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+        let mut previous_length = solar_day(first.pred_opt()?).day_length();
+
+        let mut sunrises = Extremes::default();
+        let mut sunsets = Extremes::default();
+        let mut longest_day = (first, TimeDelta::MIN);
+        let mut shortest_day = (first, TimeDelta::MAX);
+        let mut fastest_lengthening = (first, TimeDelta::MIN);
+        let mut fastest_shortening = (first, TimeDelta::MAX);
+        let mut total_daylight = TimeDelta::zero();
+
+        for date in first.iter_days().take_while(|date| date.year() == year) {
+            let day = solar_day(date);
+            sunrises.update(&day, SolarEvent::Sunrise);
+            sunsets.update(&day, SolarEvent::Sunset);
+
+            let length = day.day_length();
+            let change = length - previous_length;
+            previous_length = length;
+            total_daylight += length;
+
+            if length > longest_day.1 {
+                longest_day = (date, length);
+            }
+            if length < shortest_day.1 {
+                shortest_day = (date, length);
+            }
+            if change > fastest_lengthening.1 {
+                fastest_lengthening = (date, change);
+            }
+            if change < fastest_shortening.1 {
+                fastest_shortening = (date, change);
+            }
+        }
+
+        Some(Self {
+            earliest_sunrise: sunrises.earliest.map(|(_, time)| time),
+            latest_sunrise: sunrises.latest.map(|(_, time)| time),
+            earliest_sunset: sunsets.earliest.map(|(_, time)| time),
+            latest_sunset: sunsets.latest.map(|(_, time)| time),
+            longest_day,
+            shortest_day,
+            fastest_lengthening,
+            fastest_shortening,
+            total_daylight,
+        })
+    }
+}
+
+/// Get how much the day length changed at a given date since the previous day, comparing the
+/// solar days built by `solar_day` for both dates.
+///
+/// Returns `None` if the previous date is out of the range of supported dates.
+pub fn day_length_change<M: SolarModel>(
+    solar_day: impl Fn(NaiveDate) -> SolarDay<M>,
+    date: NaiveDate,
+) -> Option<TimeDelta> {
+    Some(solar_day(date).day_length() - solar_day(date.pred_opt()?).day_length())
+}
+
+/// Earliest and latest time of day of an event, with their time of day in days since the mean
+/// solar noon.
+#[derive(Default)]
+struct Extremes {
+    earliest: Option<(f64, DateTime<Utc>)>,
+    latest: Option<(f64, DateTime<Utc>)>,
+}
+
+impl Extremes {
+    fn update<M: SolarModel>(&mut self, day: &SolarDay<M>, event: SolarEvent) {
+        let Some(time) = day.event_time(event) else {
+            return;
+        };
+        let time_of_day = datetime_to_julian(time) - day.mean_solar_noon();
+
+        if self
+            .earliest
+            .is_none_or(|(earliest, _)| time_of_day < earliest)
+        {
+            self.earliest = Some((time_of_day, time));
+        }
+        if self.latest.is_none_or(|(latest, _)| time_of_day > latest) {
+            self.latest = Some((time_of_day, time));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Coordinates;
+
+    #[test]
+    fn test_equator() {
+        let coord = Coordinates::new(0., 0.).unwrap();
+        let summary = AnnualSummary::new(|date| SolarDay::new(coord, date), 2024).unwrap();
+
+        // Days are about 12 hours long all year round.
+        let (_, longest) = summary.longest_day;
+        let (_, shortest) = summary.shortest_day;
+        assert!(longest - shortest < TimeDelta::minutes(2));
+        assert_eq!(summary.total_daylight.num_hours() / 366, 12);
+
+        // The equation of time shifts sunrise by about half an hour over the year.
+        let earliest = summary.earliest_sunrise.unwrap();
+        let latest = summary.latest_sunrise.unwrap();
+        assert_eq!(earliest.month(), 11);
+        assert_eq!(latest.month(), 2);
+    }
+
+    #[test]
+    fn test_polar() {
+        let coord = Coordinates::new(80., 0.).unwrap();
+        let summary = AnnualSummary::new(|date| SolarDay::new(coord, date), 2024).unwrap();
+
+        assert_eq!(summary.longest_day.1, TimeDelta::days(1));
+        assert_eq!(summary.shortest_day.1, TimeDelta::zero());
+        // The first day of the midnight sun, and the year starts during the polar night.
+        assert_eq!(summary.longest_day.0.month(), 4);
+        assert_eq!(
+            summary.shortest_day.0,
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+        );
+
+        // The fastest changes happen when the sun starts rising or stops setting.
+        assert!(summary.fastest_lengthening.1 > TimeDelta::hours(1));
+        assert!(summary.fastest_shortening.1 < -TimeDelta::hours(1));
+    }
+
+    #[test]
+    fn test_day_length_change() {
+        let coord = Coordinates::new(59.9139, 10.7522).unwrap();
+        let solar_day = |date| SolarDay::new(coord, date);
+
+        let spring = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+        let change = day_length_change(solar_day, spring).unwrap();
+        assert!((5..=7).contains(&change.num_minutes()));
+
+        let solstice = NaiveDate::from_ymd_opt(2024, 6, 20).unwrap();
+        let change = day_length_change(solar_day, solstice).unwrap();
+        assert!(change.abs() < TimeDelta::seconds(30));
+    }
+}
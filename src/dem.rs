@@ -0,0 +1,630 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use core::f64::consts::PI;
+use core::fmt::{self, Display, Formatter};
+use std::path::Path;
+use std::string::String;
+use std::vec::Vec;
+
+use crate::Coordinates;
+use crate::math::{asin, atan2, cos, sin};
+use crate::refraction::{EARTH_RADIUS, TERRESTRIAL_REFRACTION};
+
+/// Value marking missing samples in signed 16-bit heightmaps, such as voids of SRTM tiles.
+const I16_NODATA: i16 = i16::MIN;
+
+/// Number of meters in a degree of latitude.
+const METERS_PER_DEGREE: f64 = EARTH_RADIUS * PI / 180.;
+
+/// Smallest interval between two directions of a skyline, in degrees.
+const MIN_AZIMUTH_STEP: f64 = 0.01;
+
+/// Error while reading a [`Dem`] or computing a horizon from it.
+#[derive(Debug)]
+pub enum DemError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// The file is not a valid grid, with a description of the issue.
+    Parse(String),
+    /// The number of samples does not match the size of the grid.
+    InvalidSize {
+        /// Number of samples expected from the size of the grid.
+        expected: usize,
+        /// Number of samples found.
+        found: usize,
+    },
+    /// The grid is empty or its cells have no size.
+    InvalidGeometry,
+    /// The observer is outside of the grid, or over missing samples.
+    OutOfBounds,
+    /// The options of [`Dem::horizon`] are out of range, see [`HorizonOptions`].
+    InvalidOptions,
+}
+
+impl Display for DemError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DemError::Io(err) => write!(f, "could not read the elevation model: {err}"),
+            DemError::Parse(message) => write!(f, "invalid elevation model: {message}"),
+            DemError::InvalidSize { expected, found } => {
+                write!(f, "expected {expected} samples, found {found}")
+            }
+            DemError::InvalidGeometry => write!(f, "the elevation model is empty"),
+            DemError::OutOfBounds => write!(f, "the observer is outside of the elevation model"),
+            DemError::InvalidOptions => write!(f, "invalid options for the horizon"),
+        }
+    }
+}
+
+impl std::error::Error for DemError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DemError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for DemError {
+    fn from(err: std::io::Error) -> Self {
+        DemError::Io(err)
+    }
+}
+
+/// Extent of a grid of samples in geographic coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct DemGeometry {
+    /// Number of columns, from west to east.
+    pub columns: usize,
+    /// Number of rows, from north to south.
+    pub rows: usize,
+    /// Longitude of the western edge of the grid, in degrees.
+    pub west: f64,
+    /// Latitude of the southern edge of the grid, in degrees.
+    pub south: f64,
+    /// Size of a cell, in degrees of latitude and longitude.
+    pub cell_size: f64,
+}
+
+/// Encoding of the samples of a raw heightmap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SampleFormat {
+    /// Signed 16-bit integers in big-endian order, as in SRTM `.hgt` tiles.
+    I16BigEndian,
+    /// Signed 16-bit integers in little-endian order.
+    I16LittleEndian,
+    /// 32-bit floats in little-endian order.
+    F32LittleEndian,
+}
+
+impl SampleFormat {
+    fn size(self) -> usize {
+        match self {
+            SampleFormat::I16BigEndian | SampleFormat::I16LittleEndian => 2,
+            SampleFormat::F32LittleEndian => 4,
+        }
+    }
+
+    fn decode(self, bytes: &[u8]) -> f64 {
+        let sample = match self {
+            SampleFormat::I16BigEndian => i16::from_be_bytes([bytes[0], bytes[1]]),
+            SampleFormat::I16LittleEndian => i16::from_le_bytes([bytes[0], bytes[1]]),
+            SampleFormat::F32LittleEndian => {
+                return f64::from(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+            }
+        };
+
+        if sample == I16_NODATA {
+            f64::NAN
+        } else {
+            f64::from(sample)
+        }
+    }
+}
+
+/// A digital elevation model: a grid of heights above the sea level, in meters, in geographic
+/// coordinates.
+///
+/// Missing samples are stored as NaN.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dem {
+    geometry: DemGeometry,
+    heights: Vec<f64>,
+}
+
+impl Dem {
+    /// Initialize given the extent of the grid and its heights, in meters, row by row from the
+    /// north-west corner.
+    pub fn new(geometry: DemGeometry, heights: Vec<f64>) -> Result<Self, DemError> {
+        let Some(expected) = geometry.columns.checked_mul(geometry.rows) else {
+            return Err(DemError::InvalidGeometry);
+        };
+
+        if expected == 0 || geometry.cell_size <= 0. || geometry.cell_size.is_nan() {
+            return Err(DemError::InvalidGeometry);
+        }
+        if heights.len() != expected {
+            return Err(DemError::InvalidSize {
+                expected,
+                found: heights.len(),
+            });
+        }
+
+        Ok(Self { geometry, heights })
+    }
+
+    /// Read a grid in the ESRI ASCII format, whose coordinates must be longitudes and latitudes.
+    ///
+    /// See <https://desktop.arcgis.com/en/arcmap/latest/manage-data/raster-and-images/esri-ascii-raster-format.htm>.
+    pub fn read_esri_ascii(path: impl AsRef<Path>) -> Result<Self, DemError> {
+        Self::parse_esri_ascii(&std::fs::read_to_string(path)?)
+    }
+
+    /// Read a raw heightmap, which only contains samples row by row from the north-west corner.
+    pub fn read_heightmap(
+        path: impl AsRef<Path>,
+        geometry: DemGeometry,
+        format: SampleFormat,
+    ) -> Result<Self, DemError> {
+        let bytes = std::fs::read(path)?;
+
+        if bytes.len() % format.size() != 0 {
+            return Err(DemError::Parse(String::from(
+                "the file is not made of whole samples",
+            )));
+        }
+
+        let heights = bytes
+            .chunks_exact(format.size())
+            .map(|sample| format.decode(sample))
+            .collect();
+        Self::new(geometry, heights)
+    }
+
+    /// Get the extent of the grid.
+    pub fn geometry(&self) -> DemGeometry {
+        self.geometry
+    }
+
+    /// Get the height at given coordinates in meters, interpolated between the centers of the
+    /// cells.
+    ///
+    /// Returns `None` outside of the grid or next to missing samples.
+    pub fn height(&self, coord: Coordinates) -> Option<f64> {
+        let geometry = &self.geometry;
+        let (x, y) = self.position(coord)?;
+        let (column, row) = (x as usize, y as usize);
+        let (dx, dy) = (x - column as f64, y - row as f64);
+        let neighbours = [
+            (column, row, (1. - dx) * (1. - dy)),
+            (column + 1, row, dx * (1. - dy)),
+            (column, row + 1, (1. - dx) * dy),
+            (column + 1, row + 1, dx * dy),
+        ];
+
+        // Neighbours without weight may be out of the grid or missing.
+        let height: f64 = neighbours
+            .iter()
+            .filter(|&&(_, _, weight)| weight > 0.)
+            .map(|&(column, row, weight)| self.heights[row * geometry.columns + column] * weight)
+            .sum();
+
+        (!height.is_nan()).then_some(height)
+    }
+
+    /// Get the position of given coordinates in the grid, in cells from the center of the
+    /// north-west cell, or `None` outside of the centers of the cells.
+    fn position(&self, coord: Coordinates) -> Option<(f64, f64)> {
+        let geometry = &self.geometry;
+        let north = geometry.south + geometry.rows as f64 * geometry.cell_size;
+        let x = (coord.lon() - geometry.west) / geometry.cell_size - 0.5;
+        let y = (north - coord.lat()) / geometry.cell_size - 0.5;
+
+        let inside = (0. ..=(geometry.columns - 1) as f64).contains(&x)
+            && (0. ..=(geometry.rows - 1) as f64).contains(&y);
+        inside.then_some((x, y))
+    }
+
+    /// Compute the skyline seen from given coordinates by casting rays across the grid, to be
+    /// used as a [`HorizonProfile`].
+    ///
+    /// The drop of the terrain due to the curvature of the Earth is accounted for, reduced by the
+    /// terrestrial refraction. Rays stop at the edge of the grid, and directions in which they
+    /// leave it immediately are omitted.
+    ///
+    /// Returns [`DemError::InvalidOptions`] if the radius is not positive and finite, if the
+    /// azimuth step is below 0.01° or if the observer height is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use chrono::NaiveDate;
+    /// use sunrise::{Coordinates, Dem, HorizonOptions, HorizonProfile, SolarDay};
+    ///
+    /// let coord = Coordinates::new(46.6, 8.0).unwrap();
+    /// let dem = Dem::read_esri_ascii("alps.asc").unwrap();
+    /// let points = dem.horizon(coord, &HorizonOptions::default()).unwrap();
+    /// let horizon = HorizonProfile::new(&points).unwrap();
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 12, 21).unwrap();
+    /// let sunrise = SolarDay::new(coord, date).visible_sunrise(&horizon);
+    /// ```
+    ///
+    /// [`HorizonProfile`]: crate::HorizonProfile
+    pub fn horizon(
+        &self,
+        coord: Coordinates,
+        options: &HorizonOptions,
+    ) -> Result<Vec<(f64, f64)>, DemError> {
+        let valid = options.radius.is_finite()
+            && options.radius > 0.
+            && options.azimuth_step >= MIN_AZIMUTH_STEP
+            && options.observer_height.is_finite();
+        if !valid {
+            return Err(DemError::InvalidOptions);
+        }
+
+        let observer = self.height(coord).ok_or(DemError::OutOfBounds)? + options.observer_height;
+        let step = (self.geometry.cell_size * METERS_PER_DEGREE / 2.).max(1.);
+        // Rays going further than the antipode would come back.
+        let max_distance = options.radius.min(PI * EARTH_RADIUS);
+        let radius = EARTH_RADIUS / (1. - TERRESTRIAL_REFRACTION);
+
+        let mut points = Vec::new();
+        let mut azimuth = 0.;
+
+        while azimuth < 360. {
+            let mut highest = None::<f64>;
+            let mut distance = step;
+
+            while distance <= max_distance {
+                let Some(target) = destination(coord, azimuth, distance)
+                    .filter(|&target| self.position(target).is_some())
+                else {
+                    break;
+                };
+                // Missing samples are skipped.
+                let Some(height) = self.height(target) else {
+                    distance += step;
+                    continue;
+                };
+
+                let drop = distance * distance / (2. * radius);
+                let altitude = atan2(height - drop - observer, distance);
+                highest = Some(highest.map_or(altitude, |highest| highest.max(altitude)));
+                distance += step;
+            }
+
+            if let Some(highest) = highest {
+                points.push((azimuth, highest.to_degrees()));
+            }
+            azimuth += options.azimuth_step;
+        }
+
+        if points.is_empty() {
+            return Err(DemError::OutOfBounds);
+        }
+
+        Ok(points)
+    }
+
+    /// Parse a grid in the ESRI ASCII format.
+    fn parse_esri_ascii(content: &str) -> Result<Self, DemError> {
+        let mut tokens = content.split_whitespace().peekable();
+        let mut columns = None;
+        let mut rows = None;
+        let mut west = None;
+        let mut south = None;
+        let mut centered = false;
+        let mut cell_size = None;
+        let mut nodata = None;
+
+        while let Some(key) = tokens.next_if(|token| token.starts_with(char::is_alphabetic)) {
+            let value = tokens
+                .next()
+                .ok_or_else(|| DemError::Parse(std::format!("missing value for {key}")))?;
+            let number = value
+                .parse::<f64>()
+                .map_err(|_| DemError::Parse(std::format!("invalid value for {key}: {value}")))?;
+
+            // Sizes must be whole numbers of cells.
+            let size = || {
+                value
+                    .parse::<usize>()
+                    .map_err(|_| DemError::Parse(std::format!("invalid size for {key}: {value}")))
+            };
+
+            match key.to_ascii_lowercase().as_str() {
+                "ncols" => columns = Some(size()?),
+                "nrows" => rows = Some(size()?),
+                "xllcorner" => west = Some(number),
+                "yllcorner" => south = Some(number),
+                "xllcenter" => (west, centered) = (Some(number), true),
+                "yllcenter" => (south, centered) = (Some(number), true),
+                "cellsize" => cell_size = Some(number),
+                "nodata_value" => nodata = Some(number),
+                _ => return Err(DemError::Parse(std::format!("unknown key {key}"))),
+            }
+        }
+
+        let missing = |key: &str| DemError::Parse(std::format!("missing {key}"));
+        let cell_size = cell_size.ok_or_else(|| missing("cellsize"))?;
+        let offset = if centered { cell_size / 2. } else { 0. };
+        let geometry = DemGeometry {
+            columns: columns.ok_or_else(|| missing("ncols"))?,
+            rows: rows.ok_or_else(|| missing("nrows"))?,
+            west: west.ok_or_else(|| missing("xllcorner"))? - offset,
+            south: south.ok_or_else(|| missing("yllcorner"))? - offset,
+            cell_size,
+        };
+
+        let heights = tokens
+            .map(|token| match token.parse::<f64>() {
+                Ok(height) if Some(height) == nodata => Ok(f64::NAN),
+                Ok(height) => Ok(height),
+                Err(_) => Err(DemError::Parse(std::format!("invalid height {token}"))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(geometry, heights)
+    }
+}
+
+/// Options of [`Dem::horizon`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct HorizonOptions {
+    radius: f64,
+    azimuth_step: f64,
+    observer_height: f64,
+}
+
+impl Default for HorizonOptions {
+    fn default() -> Self {
+        Self {
+            radius: 20000.,
+            azimuth_step: 1.,
+            observer_height: 2.,
+        }
+    }
+}
+
+impl HorizonOptions {
+    /// Specify the distance (in meters) up to which the terrain is searched, which must be
+    /// positive and finite. This defaults to 20 km if not specified.
+    pub fn with_radius(mut self, radius: f64) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Specify the interval (in degrees) between two directions of the skyline, which must be at
+    /// least 0.01°. This defaults to 1° if not specified.
+    pub fn with_azimuth_step(mut self, azimuth_step: f64) -> Self {
+        self.azimuth_step = azimuth_step;
+        self
+    }
+
+    /// Specify the height (in meters) of the eyes of the observer above the ground. This
+    /// defaults to 2 m if not specified.
+    pub fn with_observer_height(mut self, observer_height: f64) -> Self {
+        self.observer_height = observer_height;
+        self
+    }
+}
+
+/// Calculates the coordinates reached by travelling a given distance (in meters) along a great
+/// circle in a given direction (in degrees clockwise from north).
+fn destination(coord: Coordinates, azimuth: f64, distance: f64) -> Option<Coordinates> {
+    let latitude = coord.lat().to_radians();
+    let bearing = azimuth.to_radians();
+    let angle = distance / EARTH_RADIUS;
+
+    let target = asin(sin(latitude) * cos(angle) + cos(latitude) * sin(angle) * cos(bearing));
+    let longitude = coord.lon().to_radians()
+        + atan2(
+            sin(bearing) * sin(angle) * cos(latitude),
+            cos(angle) - sin(latitude) * sin(target),
+        );
+
+    let longitude = (longitude.to_degrees() + 540.) % 360. - 180.;
+    Coordinates::new(target.to_degrees(), longitude)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    /// A plain at 100 m with a 1100 m high ridge 1 km east of its center.
+    fn ridge() -> Dem {
+        let geometry = DemGeometry {
+            columns: 201,
+            rows: 201,
+            west: -0.0009 * 100.5,
+            south: -0.0009 * 100.5,
+            cell_size: 0.0009,
+        };
+        let heights = (0..201 * 201)
+            .map(|index| if index % 201 == 110 { 1100. } else { 100. })
+            .collect();
+        Dem::new(geometry, heights).unwrap()
+    }
+
+    #[test]
+    fn test_invalid_size() {
+        let geometry = DemGeometry {
+            columns: 2,
+            rows: 2,
+            west: 0.,
+            south: 0.,
+            cell_size: 1.,
+        };
+        assert!(matches!(
+            Dem::new(geometry, std::vec![0.; 3]),
+            Err(DemError::InvalidSize {
+                expected: 4,
+                found: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_geometry() {
+        let geometry = DemGeometry {
+            columns: 0,
+            rows: 2,
+            west: 0.,
+            south: 0.,
+            cell_size: 1.,
+        };
+        assert!(matches!(
+            Dem::new(geometry, std::vec![]),
+            Err(DemError::InvalidGeometry)
+        ));
+
+        let geometry = DemGeometry {
+            columns: usize::MAX,
+            rows: 10,
+            ..geometry
+        };
+        assert!(matches!(
+            Dem::new(geometry, std::vec![]),
+            Err(DemError::InvalidGeometry)
+        ));
+    }
+
+    #[test]
+    fn test_height() {
+        let dem = ridge();
+        let center = Coordinates::new(0., 0.).unwrap();
+        assert_relative_eq!(dem.height(center).unwrap(), 100.);
+        let ridge = Coordinates::new(0., 0.0009 * 10.).unwrap();
+        assert_relative_eq!(dem.height(ridge).unwrap(), 1100.);
+        let slope = Coordinates::new(0., 0.0009 * 9.5).unwrap();
+        assert_relative_eq!(dem.height(slope).unwrap(), 600., epsilon = 1e-6);
+        assert_eq!(dem.height(Coordinates::new(1., 0.).unwrap()), None);
+    }
+
+    #[test]
+    fn test_horizon() {
+        let dem = ridge();
+        let center = Coordinates::new(0., 0.).unwrap();
+        let options = HorizonOptions::default()
+            .with_radius(5000.)
+            .with_azimuth_step(90.)
+            .with_observer_height(0.);
+        let points = dem.horizon(center, &options).unwrap();
+        assert_eq!(points.len(), 4);
+
+        // The ridge is 1000 m above the observer and about 1 km away.
+        assert_eq!(points[1].0, 90.);
+        assert_relative_eq!(points[1].1, 45., epsilon = 1.);
+
+        // The plain drops with the curvature of the Earth.
+        assert!(points[0].1 < 0. && points[0].1 > -0.01);
+        assert_eq!(points[0].1, points[2].1);
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        let dem = ridge();
+        let center = Coordinates::new(0., 0.).unwrap();
+        let invalid = [
+            HorizonOptions::default().with_radius(f64::INFINITY),
+            HorizonOptions::default().with_radius(0.),
+            HorizonOptions::default().with_radius(f64::NAN),
+            HorizonOptions::default().with_azimuth_step(1e-300),
+            HorizonOptions::default().with_azimuth_step(-1.),
+            HorizonOptions::default().with_observer_height(f64::NAN),
+        ];
+        for options in invalid {
+            assert!(matches!(
+                dem.horizon(center, &options),
+                Err(DemError::InvalidOptions)
+            ));
+        }
+
+        // Rays stop at the edge of the grid, even when the radius is huge.
+        let options = HorizonOptions::default()
+            .with_radius(1e15)
+            .with_azimuth_step(90.);
+        assert_eq!(dem.horizon(center, &options).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_esri_ascii() {
+        let content = "ncols 3\nnrows 2\nxllcenter 10.0\nyllcenter 45.0\ncellsize 0.5\n\
+            NODATA_value -9999\n1 2 3\n4 -9999 6\n";
+        let dem = Dem::parse_esri_ascii(content).unwrap();
+        assert_eq!(
+            dem.geometry(),
+            DemGeometry {
+                columns: 3,
+                rows: 2,
+                west: 9.75,
+                south: 44.75,
+                cell_size: 0.5
+            }
+        );
+        let coord = Coordinates::new(45.5, 10.).unwrap();
+        assert_relative_eq!(dem.height(coord).unwrap(), 1.);
+        assert_eq!(dem.height(Coordinates::new(45., 10.5).unwrap()), None);
+
+        assert!(matches!(
+            Dem::parse_esri_ascii("ncols 3\nnrows 2\n1 2 3 4 5 6"),
+            Err(DemError::Parse(_))
+        ));
+        for size in ["-3", "2.5", "1e19", "99999999999999999999"] {
+            assert!(matches!(
+                Dem::parse_esri_ascii(&content.replace("ncols 3", &std::format!("ncols {size}"))),
+                Err(DemError::Parse(_))
+            ));
+        }
+        assert!(matches!(
+            Dem::parse_esri_ascii(&content.replace("6\n", "")),
+            Err(DemError::InvalidSize {
+                expected: 6,
+                found: 5
+            })
+        ));
+    }
+
+    #[test]
+    fn test_sample_format() {
+        assert_eq!(SampleFormat::I16BigEndian.decode(&[0x01, 0x02]), 258.);
+        assert_eq!(SampleFormat::I16LittleEndian.decode(&[0x01, 0x02]), 513.);
+        assert!(SampleFormat::I16BigEndian.decode(&[0x80, 0x00]).is_nan());
+        assert_eq!(
+            SampleFormat::F32LittleEndian.decode(&1.5f32.to_le_bytes()),
+            1.5
+        );
+    }
+
+    #[test]
+    fn test_destination() {
+        let coord = Coordinates::new(0., 0.).unwrap();
+        let east = destination(coord, 90., METERS_PER_DEGREE).unwrap();
+        assert_relative_eq!(east.lat(), 0., epsilon = 1e-9);
+        assert_relative_eq!(east.lon(), 1., epsilon = 1e-9);
+        let north = destination(coord, 0., METERS_PER_DEGREE).unwrap();
+        assert_relative_eq!(north.lat(), 1., epsilon = 1e-9);
+    }
+}
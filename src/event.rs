@@ -20,6 +20,15 @@
 // FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
 // IN THE SOFTWARE.
 
+use core::f64::consts::{FRAC_PI_2, PI};
+
+use chrono::{DateTime, Utc};
+
+use crate::refraction::Refraction;
+
+/// Mean apparent radius of the sun, in radians.
+pub(crate) const SOLAR_SEMI_DIAMETER: f64 = 16. / 60. * PI / 180.;
+
 /// Type of dawn or dusk computation.
 ///
 /// If you are not sure which one to pick you probably want to use `Civil`. See
@@ -67,27 +76,202 @@ pub enum SolarEvent {
     /// Dusk is the time that marks the end of twilight after sunset.
     Dusk(DawnType),
     /// The point in time where the sun reaches a given elevation.
+    ///
+    /// Prefer [`SolarEvent::elevation`] or [`SolarEvent::elevation_degrees`] which validate their
+    /// input.
     Elevation {
-        /// Sun's elevation, **in radians**.
+        /// Sun's elevation above the horizon, **in radians**. Negative values are below the
+        /// horizon.
         elevation: f64,
         /// `true` if this is the morning, `false` otherwise.
         morning: bool,
     },
+    /// Solar noon is the upper culmination of the Sun, when it crosses the meridian at its
+    /// highest elevation of the day.
+    Noon,
+    /// Solar midnight is the lower culmination of the Sun preceding solar noon, when it reaches
+    /// its lowest elevation.
+    Midnight,
 }
 
 impl SolarEvent {
-    pub(crate) fn angle(&self) -> f64 {
+    /// The point in time where the sun reaches a given elevation above the horizon (**in
+    /// radians**), negative values being below the horizon.
+    ///
+    /// Return `None` if the elevation is out of range (`abs(elevation) > pi/2`).
+    pub const fn elevation(elevation: f64, morning: bool) -> Option<Self> {
+        if elevation.is_nan() || elevation < -FRAC_PI_2 || elevation > FRAC_PI_2 {
+            return None;
+        }
+
+        Some(SolarEvent::Elevation { elevation, morning })
+    }
+
+    /// The point in time where the sun reaches a given elevation above the horizon (**in
+    /// degrees**), negative values being below the horizon.
+    ///
+    /// Return `None` if the elevation is out of range (`abs(elevation) > 90`).
+    pub const fn elevation_degrees(elevation: f64, morning: bool) -> Option<Self> {
+        if elevation.is_nan() || elevation < -90. || elevation > 90. {
+            return None;
+        }
+
+        Some(SolarEvent::Elevation {
+            elevation: elevation.to_radians(),
+            morning,
+        })
+    }
+
+    /// The point in time where the sun reaches a given depression below the horizon (**in
+    /// radians**), negative values being above the horizon.
+    ///
+    /// Before 4.0.0, the `elevation` of [`SolarEvent::Elevation`] was interpreted as a
+    /// depression: replacing `SolarEvent::Elevation { elevation, morning }` with
+    /// `SolarEvent::depression(elevation, morning)` keeps the previous behavior.
+    ///
+    /// Return `None` if the depression is out of range (`abs(depression) > pi/2`).
+    pub const fn depression(depression: f64, morning: bool) -> Option<Self> {
+        Self::elevation(-depression, morning)
+    }
+
+    /// Geometric depression of the center of the sun below the horizon at which the event
+    /// happens, in radians.
+    ///
+    /// `limb` is the elevation of the limb of the sun which defines sunrise and sunset relative
+    /// to its center, and `horizon_dip` is the depression of the visible horizon on which the sun
+    /// rises and sets, both in radians.
+    ///
+    /// Returns `None` for culminations, which do not depend on the elevation of the sun.
+    pub(crate) fn angle(&self, refraction: Refraction, limb: f64, horizon_dip: f64) -> Option<f64> {
         match self {
-            SolarEvent::Sunrise | SolarEvent::Sunset => f64::to_radians(5.) / 6.,
-            SolarEvent::Dusk(t) | SolarEvent::Dawn(t) => t.positive_angle(),
-            SolarEvent::Elevation { elevation, .. } => *elevation,
+            SolarEvent::Sunrise | SolarEvent::Sunset => {
+                Some(limb + refraction.at_horizon(horizon_dip) + horizon_dip)
+            }
+            SolarEvent::Dusk(t) | SolarEvent::Dawn(t) => Some(t.positive_angle()),
+            SolarEvent::Elevation { elevation, .. } => {
+                Some(-refraction.event_elevation(*elevation))
+            }
+            SolarEvent::Noon | SolarEvent::Midnight => None,
         }
     }
 
     pub(crate) fn is_morning(&self) -> bool {
         matches!(
             self,
-            SolarEvent::Sunrise | SolarEvent::Dawn(_) | SolarEvent::Elevation { morning: true, .. }
+            SolarEvent::Sunrise
+                | SolarEvent::Dawn(_)
+                | SolarEvent::Elevation { morning: true, .. }
+                | SolarEvent::Midnight
         )
     }
 }
+
+/// A culmination of the sun, see [`SolarEvent::Noon`] and [`SolarEvent::Midnight`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Culmination {
+    /// Time of the culmination.
+    pub time: DateTime<Utc>,
+    /// Elevation of the center of the sun above the horizon at the culmination, **in radians**.
+    pub elevation: f64,
+}
+
+/// Outcome of the computation of a solar event.
+///
+/// An event defined by an elevation of the sun does not happen when the sun stays on the same
+/// side of this elevation for the whole day, this tells on which side it stays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Occurrence<T = DateTime<Utc>> {
+    /// The event happens at the given time.
+    Occurs(T),
+    /// The sun stays above the elevation of the event for the whole day (e.g., sunset during the
+    /// midnight sun).
+    AlwaysAbove,
+    /// The sun stays below the elevation of the event for the whole day (e.g., sunrise during the
+    /// polar night).
+    AlwaysBelow,
+}
+
+impl<T> Occurrence<T> {
+    /// Get the value of the event if it occurs, `None` otherwise.
+    pub fn occurs(self) -> Option<T> {
+        match self {
+            Occurrence::Occurs(value) => Some(value),
+            Occurrence::AlwaysAbove | Occurrence::AlwaysBelow => None,
+        }
+    }
+
+    /// Apply a function to the value of the event if it occurs.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Occurrence<U> {
+        match self {
+            Occurrence::Occurs(value) => Occurrence::Occurs(f(value)),
+            Occurrence::AlwaysAbove => Occurrence::AlwaysAbove,
+            Occurrence::AlwaysBelow => Occurrence::AlwaysBelow,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::FRAC_PI_4;
+
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_elevation() {
+        assert!(SolarEvent::elevation(f64::NAN, true).is_none());
+        assert!(SolarEvent::elevation(2., true).is_none());
+        assert!(SolarEvent::elevation_degrees(-91., true).is_none());
+        assert_eq!(
+            SolarEvent::elevation(FRAC_PI_4, false),
+            Some(SolarEvent::Elevation {
+                elevation: FRAC_PI_4,
+                morning: false
+            })
+        );
+        assert_relative_eq!(
+            SolarEvent::elevation_degrees(45., true)
+                .unwrap()
+                .angle(Refraction::None, SOLAR_SEMI_DIAMETER, 0.)
+                .unwrap(),
+            -FRAC_PI_4
+        );
+
+        // The standard refraction matches the one of the standard atmosphere.
+        let atmospheric = Refraction::Atmospheric {
+            pressure: 1010.,
+            temperature: 10.,
+        };
+        let event = SolarEvent::elevation_degrees(2., true).unwrap();
+        assert_relative_eq!(
+            event
+                .angle(Refraction::Standard, SOLAR_SEMI_DIAMETER, 0.)
+                .unwrap(),
+            event.angle(atmospheric, SOLAR_SEMI_DIAMETER, 0.).unwrap()
+        );
+        assert!(
+            event
+                .angle(Refraction::Standard, SOLAR_SEMI_DIAMETER, 0.)
+                .unwrap()
+                > -f64::to_radians(2.)
+        );
+    }
+
+    #[test]
+    fn test_depression() {
+        assert_relative_eq!(
+            SolarEvent::depression(0.1, true)
+                .unwrap()
+                .angle(Refraction::Standard, SOLAR_SEMI_DIAMETER, 0.)
+                .unwrap(),
+            0.1
+        );
+        assert_relative_eq!(
+            SolarEvent::elevation(-0.1, true)
+                .unwrap()
+                .angle(Refraction::Standard, SOLAR_SEMI_DIAMETER, 0.)
+                .unwrap(),
+            0.1
+        );
+    }
+}
@@ -0,0 +1,139 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use crate::math::rem_euclid;
+
+/// Skyline seen by an observer, as the altitude of the visible horizon for each azimuth, which
+/// allows to compute when the sun appears behind mountains or buildings.
+///
+/// The altitude is interpolated linearly between the points of the profile, wrapping around
+/// north.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use sunrise::{Coordinates, HorizonProfile, SolarDay};
+///
+/// // A valley oriented north-south with mountains rising 15° to the east and 10° to the west.
+/// let points = [(0., 2.), (90., 15.), (180., 2.), (270., 10.)];
+/// let horizon = HorizonProfile::new(&points).unwrap();
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+/// let coord = Coordinates::new(46.5, 7.9).unwrap();
+/// let sunrise = SolarDay::new(coord, date).visible_sunrise(&horizon);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct HorizonProfile<'a> {
+    points: &'a [(f64, f64)],
+}
+
+impl<'a> HorizonProfile<'a> {
+    /// Initialize given points made of an azimuth clockwise from true north and the altitude of
+    /// the horizon in this direction, **in degrees**.
+    ///
+    /// Returns `None` if there are no points, or if the azimuths are not sorted in `[0, 360)`.
+    pub fn new(points: &'a [(f64, f64)]) -> Option<Self> {
+        let in_range = points
+            .iter()
+            .all(|&(azimuth, altitude)| (0. ..360.).contains(&azimuth) && altitude.is_finite());
+        let sorted = points.windows(2).all(|pair| pair[0].0 < pair[1].0);
+
+        if points.is_empty() || !in_range || !sorted {
+            return None;
+        }
+
+        Some(Self { points })
+    }
+
+    /// Get the points of the profile.
+    pub fn points(&self) -> &'a [(f64, f64)] {
+        self.points
+    }
+
+    /// Get the altitude of the horizon at a given azimuth, **in radians**.
+    pub fn altitude(&self, azimuth: f64) -> f64 {
+        let azimuth = rem_euclid(azimuth.to_degrees(), 360.);
+        let index = self.points.partition_point(|&(point, _)| point <= azimuth);
+
+        // Neighbours of the azimuth, wrapping around north.
+        let (before, after) = match index {
+            0 => {
+                let (last, altitude) = self.points[self.points.len() - 1];
+                ((last - 360., altitude), self.points[0])
+            }
+            index if index == self.points.len() => {
+                let (first, altitude) = self.points[0];
+                (self.points[index - 1], (first + 360., altitude))
+            }
+            index => (self.points[index - 1], self.points[index]),
+        };
+
+        let span = after.0 - before.0;
+        let altitude = if span > 0. {
+            before.1 + (after.1 - before.1) * (azimuth - before.0) / span
+        } else {
+            before.1
+        };
+
+        altitude.to_radians()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_new() {
+        assert!(HorizonProfile::new(&[]).is_none());
+        assert!(HorizonProfile::new(&[(360., 0.)]).is_none());
+        assert!(HorizonProfile::new(&[(90., 0.), (10., 0.)]).is_none());
+        assert!(HorizonProfile::new(&[(0., f64::NAN)]).is_none());
+        assert!(HorizonProfile::new(&[(0., 1.), (180., 2.)]).is_some());
+    }
+
+    #[test]
+    fn test_altitude() {
+        let points = [(90., 10.), (180., 20.), (270., 0.)];
+        let horizon = HorizonProfile::new(&points).unwrap();
+        let altitude = |azimuth: f64| horizon.altitude(azimuth.to_radians()).to_degrees();
+
+        assert_relative_eq!(altitude(90.), 10.);
+        assert_relative_eq!(altitude(135.), 15.);
+        assert_relative_eq!(altitude(225.), 10.);
+        // Wrapping around north.
+        assert_relative_eq!(altitude(0.), 5.);
+        assert_relative_eq!(altitude(315.), 2.5);
+        assert_relative_eq!(altitude(45.), 7.5);
+        assert_relative_eq!(altitude(-45.), 2.5);
+    }
+
+    #[test]
+    fn test_single_point() {
+        let points = [(120., 3.)];
+        let horizon = HorizonProfile::new(&points).unwrap();
+        assert_relative_eq!(horizon.altitude(0.), f64::to_radians(3.));
+        assert_relative_eq!(horizon.altitude(2.), f64::to_radians(3.));
+    }
+}
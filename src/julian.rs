@@ -20,20 +20,140 @@
 // FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
 // IN THE SOFTWARE.
 
-use chrono::{NaiveDate, NaiveTime};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+use crate::math::floor;
 
 const SECONDS_IN_A_DAY: f64 = 86400.;
+const DAYS_IN_A_CENTURY: f64 = 36525.;
 const UNIX_EPOCH_JULIAN_DAY: f64 = 2440587.5;
+const MODIFIED_JULIAN_DAY_OFFSET: f64 = 2400000.5;
+pub(crate) const J2000: f64 = 2451545.;
 const NOON_TIME: NaiveTime = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
 
+/// A point in time as a Julian day, the number of days elapsed since noon on January 1, 4713 BC
+/// in the proleptic Julian calendar.
+///
+/// The scale of the time is the one of the value it was converted from, which is the UTC for
+/// `chrono` types.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use sunrise::JulianDay;
+///
+/// let day = JulianDay::from(Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap());
+/// assert_eq!(day, JulianDay::J2000);
+/// assert_eq!(day.modified(), 51544.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct JulianDay(f64);
+
+impl JulianDay {
+    /// The J2000.0 epoch, noon on January 1, 2000.
+    pub const J2000: Self = Self(J2000);
+
+    /// The Unix epoch, midnight on January 1, 1970.
+    pub const UNIX_EPOCH: Self = Self(UNIX_EPOCH_JULIAN_DAY);
+
+    /// Initialize given a number of days.
+    pub const fn new(day: f64) -> Self {
+        Self(day)
+    }
+
+    /// Initialize given a modified Julian day, which starts at midnight on November 17, 1858.
+    pub const fn from_modified(day: f64) -> Self {
+        Self(day + MODIFIED_JULIAN_DAY_OFFSET)
+    }
+
+    /// Initialize given a Unix timestamp, in seconds.
+    pub fn from_timestamp(timestamp: i64) -> Self {
+        Self(unix_to_julian(timestamp))
+    }
+
+    /// Get the Julian day of the mean solar noon of a date at a given longitude, when the mean
+    /// sun crosses the meridian.
+    pub fn mean_solar_noon(date: NaiveDate, longitude: f64) -> Self {
+        Self(mean_solar_noon(longitude, date))
+    }
+
+    /// Get the number of days.
+    pub const fn value(self) -> f64 {
+        self.0
+    }
+
+    /// Get the modified Julian day.
+    pub const fn modified(self) -> f64 {
+        self.0 - MODIFIED_JULIAN_DAY_OFFSET
+    }
+
+    /// Get the number of Julian centuries of 36525 days elapsed since J2000.0.
+    pub fn centuries_since_j2000(self) -> f64 {
+        centuries_since_j2000(self.0)
+    }
+
+    /// Convert to a point in time, keeping sub-second precision.
+    ///
+    /// Returns `None` if the result is out of the range supported by `chrono`.
+    pub fn to_datetime(self) -> Option<DateTime<Utc>> {
+        julian_to_datetime(self.0)
+    }
+}
+
+impl From<DateTime<Utc>> for JulianDay {
+    fn from(time: DateTime<Utc>) -> Self {
+        Self(datetime_to_julian(time))
+    }
+}
+
+impl From<NaiveDateTime> for JulianDay {
+    fn from(time: NaiveDateTime) -> Self {
+        Self::from(time.and_utc())
+    }
+}
+
+impl From<JulianDay> for f64 {
+    fn from(day: JulianDay) -> Self {
+        day.0
+    }
+}
+
 /// Converts a unix timestamp to a Julian day.
 pub(crate) fn unix_to_julian(timestamp: i64) -> f64 {
     timestamp as f64 / SECONDS_IN_A_DAY + UNIX_EPOCH_JULIAN_DAY
 }
 
-/// Converts a Julian day to a unix timestamp.
-pub(crate) fn julian_to_unix(day: f64) -> i64 {
-    ((day - UNIX_EPOCH_JULIAN_DAY) * SECONDS_IN_A_DAY) as i64
+/// Converts a point in time to a Julian day, keeping sub-second precision.
+pub(crate) fn datetime_to_julian(time: DateTime<Utc>) -> f64 {
+    unix_to_julian(time.timestamp())
+        + f64::from(time.timestamp_subsec_nanos()) / 1e9 / SECONDS_IN_A_DAY
+}
+
+/// Converts a Julian day to a point in time, keeping sub-second precision.
+///
+/// Returns `None` if the result is out of the range supported by `chrono`.
+pub(crate) fn julian_to_datetime(day: f64) -> Option<DateTime<Utc>> {
+    let seconds = (day - UNIX_EPOCH_JULIAN_DAY) * SECONDS_IN_A_DAY;
+    let whole = floor(seconds);
+    let nanos = ((seconds - whole) * 1e9) as u32;
+
+    if !whole.is_finite() || whole.abs() > i64::MAX as f64 {
+        return None;
+    }
+
+    // Rounding errors on the fractional part may produce exactly one second.
+    DateTime::from_timestamp(whole as i64, nanos.min(999_999_999))
+}
+
+/// Converts a Julian day to a number of Julian centuries since J2000.0.
+pub(crate) fn centuries_since_j2000(day: f64) -> f64 {
+    (day - J2000) / DAYS_IN_A_CENTURY
+}
+
+/// Converts a Julian day to a modified Julian day.
+pub(crate) fn modified_julian_day(day: f64) -> f64 {
+    day - MODIFIED_JULIAN_DAY_OFFSET
 }
 
 /// Calculates the time at which the sun is at its highest altitude and returns
@@ -44,8 +164,9 @@ pub(crate) fn mean_solar_noon(lon: f64, date: NaiveDate) -> f64 {
 
 #[cfg(test)]
 mod tests {
-    use crate::julian::UNIX_EPOCH_JULIAN_DAY;
-    use chrono::NaiveDate;
+    use crate::julian::{J2000, UNIX_EPOCH_JULIAN_DAY};
+    use approx::assert_relative_eq;
+    use chrono::{DateTime, NaiveDate};
 
     #[test]
     fn test_unix_to_julian() {
@@ -53,8 +174,45 @@ mod tests {
     }
 
     #[test]
-    fn test_julian_to_unix() {
-        assert_eq!(super::julian_to_unix(UNIX_EPOCH_JULIAN_DAY), 0)
+    fn test_julian_to_datetime() {
+        assert_eq!(
+            super::julian_to_datetime(UNIX_EPOCH_JULIAN_DAY),
+            DateTime::from_timestamp(0, 0)
+        );
+        assert_eq!(
+            super::julian_to_datetime(UNIX_EPOCH_JULIAN_DAY + 0.25)
+                .unwrap()
+                .timestamp(),
+            21600
+        );
+        assert_eq!(super::julian_to_datetime(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_datetime_to_julian() {
+        let time = DateTime::from_timestamp(43200, 500_000_000).unwrap();
+        assert_relative_eq!(
+            super::datetime_to_julian(time),
+            UNIX_EPOCH_JULIAN_DAY + 0.5 + 0.5 / 86400.
+        )
+    }
+
+    #[test]
+    fn test_julian_day() {
+        let time = DateTime::from_timestamp(946728000, 0).unwrap();
+        let day = super::JulianDay::from(time);
+        assert_eq!(day, super::JulianDay::J2000);
+        assert_eq!(day.centuries_since_j2000(), 0.);
+        assert_eq!(day.to_datetime(), Some(time));
+        assert_eq!(super::JulianDay::from_modified(day.modified()), day);
+        assert_eq!(
+            super::JulianDay::from_timestamp(0),
+            super::JulianDay::UNIX_EPOCH
+        );
+        assert_eq!(
+            super::JulianDay::new(J2000 + 36525.).centuries_since_j2000(),
+            1.
+        );
     }
 
     #[test]
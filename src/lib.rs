@@ -29,17 +29,53 @@ extern crate std;
 #[cfg(not(any(feature = "std", feature = "libm")))]
 compile_error!("either the `std` or `libm` feature is required");
 
+mod annual;
 mod coordinates;
+#[cfg(feature = "std")]
+mod dem;
 mod event;
+mod horizon;
 mod julian;
+mod local;
 mod math;
+mod model;
+mod noaa;
+mod phase;
+mod polar;
+mod refraction;
+mod search;
 mod solar_equation;
+mod spa;
+mod timeline;
+mod timescale;
+mod zenith;
 
 use chrono::NaiveDate;
 
+pub use crate::annual::{AnnualSummary, day_length_change};
 pub use crate::coordinates::Coordinates;
-pub use crate::event::{DawnType, SolarEvent};
-pub use crate::solar_equation::SolarDay;
+#[cfg(feature = "std")]
+pub use crate::dem::{Dem, DemError, DemGeometry, HorizonOptions, SampleFormat};
+pub use crate::event::{Culmination, DawnType, Occurrence, SolarEvent};
+pub use crate::horizon::HorizonProfile;
+pub use crate::julian::JulianDay;
+pub use crate::local::LocalSolarDay;
+pub use crate::model::{Ephemeris, SolarModel};
+pub use crate::noaa::Noaa;
+pub use crate::phase::{SolarPhase, phase_at};
+pub use crate::polar::{PolarPeriod, polar_periods};
+pub use crate::refraction::Refraction;
+pub use crate::search::{next_event, previous_event};
+pub use crate::solar_equation::{
+    Limb, Precision, Rounding, Season, SolarDay, SolarPosition, SunriseEquation, aphelion,
+    equation_of_time, from_local_apparent_time, from_local_mean_time, local_apparent_time,
+    local_mean_time, perihelion, solar_longitude_time, solar_terms, sun_distance, sun_position,
+    sun_semi_diameter,
+};
+pub use crate::spa::Spa;
+pub use crate::timeline::{Timeline, TimelineEntry};
+pub use crate::timescale::{DeltaT, TimeScale, leap_seconds};
+pub use crate::zenith::{noon_elevation_dates, zenith_passages};
 
 /// Calculates the sunrise and sunset times for the given location and date.
 ///
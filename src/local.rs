@@ -0,0 +1,105 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use chrono::{DateTime, Days, NaiveDate, TimeZone};
+
+use crate::Coordinates;
+use crate::event::SolarEvent;
+use crate::model::SolarModel;
+use crate::solar_equation::{SolarDay, SunriseEquation};
+
+/// Represent a calendar date in a given time zone at a specific location.
+///
+/// The events of a [`SolarDay`] are anchored to the mean solar noon of its date, which may fall
+/// on the previous or next date of the local calendar, especially near the date line. This
+/// instead only returns events which happen on the requested local date, including on days with
+/// a daylight saving time transition.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{FixedOffset, NaiveDate};
+/// use sunrise::{Coordinates, LocalSolarDay, SolarEvent};
+///
+/// // January 1, 2016 in Toronto
+/// let date = NaiveDate::from_ymd_opt(2016, 1, 1).unwrap();
+/// let coord = Coordinates::new(43.6532, -79.3832).unwrap();
+/// let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+///
+/// let sunrise = LocalSolarDay::new(coord, date, tz).event_time(SolarEvent::Sunrise);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocalSolarDay<Tz: TimeZone, M = SunriseEquation> {
+    date: NaiveDate,
+    timezone: Tz,
+    candidates: [Option<SolarDay<M>>; 3],
+}
+
+impl<Tz: TimeZone> LocalSolarDay<Tz> {
+    /// Initialize given position, a local date and its time zone.
+    pub fn new(coord: Coordinates, date: NaiveDate, timezone: Tz) -> Self {
+        Self::with_solar_day(date, timezone, |date| SolarDay::new(coord, date))
+    }
+}
+
+impl<Tz: TimeZone, M: SolarModel> LocalSolarDay<Tz, M> {
+    /// Initialize given a local date and its time zone, with the solar days of the neighbouring
+    /// dates built by `solar_day` instead of with the defaults of [`LocalSolarDay::new`].
+    pub fn with_solar_day(
+        date: NaiveDate,
+        timezone: Tz,
+        solar_day: impl Fn(NaiveDate) -> SolarDay<M>,
+    ) -> Self {
+        Self {
+            date,
+            timezone,
+            candidates: [
+                date.checked_sub_days(Days::new(1)).map(&solar_day),
+                Some(solar_day(date)),
+                date.checked_add_days(Days::new(1)).map(&solar_day),
+            ],
+        }
+    }
+
+    /// Get the local date.
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Get the time zone.
+    pub fn timezone(&self) -> &Tz {
+        &self.timezone
+    }
+
+    /// Get the time for when the input event will happen on the local date.
+    ///
+    /// Returns `None` if the event does not happen on this date (e.g., sunset in a polar day),
+    /// see [`SolarDay::event_occurrence`] to know why.
+    pub fn event_time(&self, event: SolarEvent) -> Option<DateTime<Tz>> {
+        // Prefer the solar day of the same date when several events fall on the local date.
+        [1, 0, 2]
+            .into_iter()
+            .filter_map(|index| self.candidates[index].as_ref()?.event_time(event))
+            .map(|time| time.with_timezone(&self.timezone))
+            .find(|time| time.date_naive() == self.date)
+    }
+}
@@ -46,9 +46,45 @@ use_std_or_libm!(
     sqrt "
         Returns the square root of a number. Returns NaN if `self` is a
         negative number other than `-0.0`.
-    "
+    ",
+    tan "Computes the tangent of a number (in radians).",
+    floor "Returns the largest integer less than or equal to a number."
 );
 
+/// Computes the four quadrant arctangent of `y` and `x` in radians.
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    #[cfg(all(not(feature = "libm"), feature = "std"))]
+    {
+        f64::atan2(y, x)
+    }
+    #[cfg(feature = "libm")]
+    {
+        libm::atan2(y, x)
+    }
+    #[cfg(not(any(feature = "libm", feature = "std")))]
+    {
+        let _ = (y, x);
+        core::f64::NAN
+    }
+}
+
+/// Raises a number to a floating point power.
+pub(crate) fn powf(x: f64, y: f64) -> f64 {
+    #[cfg(all(not(feature = "libm"), feature = "std"))]
+    {
+        f64::powf(x, y)
+    }
+    #[cfg(feature = "libm")]
+    {
+        libm::pow(x, y)
+    }
+    #[cfg(not(any(feature = "libm", feature = "std")))]
+    {
+        let _ = (x, y);
+        core::f64::NAN
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::f64::consts::PI;
@@ -90,6 +126,13 @@ mod tests {
         assert!(asin(2.).is_nan());
     }
 
+    #[test]
+    fn test_atan2() {
+        assert_relative_eq!(atan2(1., 1.), PI / 4.);
+        assert_relative_eq!(atan2(1., -1.), 3. * PI / 4.);
+        assert_relative_eq!(atan2(-1., -1.), -3. * PI / 4.);
+    }
+
     #[test]
     fn test_sqrt() {
         assert_relative_eq!(sqrt(0.), 0.);
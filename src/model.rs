@@ -0,0 +1,63 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use chrono::{DateTime, Utc};
+
+use crate::Coordinates;
+use crate::julian::JulianDay;
+use crate::solar_equation::{SolarPosition, position};
+
+/// Apparent position of the sun relative to the Earth, as computed by a [`SolarModel`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Ephemeris {
+    /// Declination of the sun, **in radians**.
+    pub declination: f64,
+    /// Difference between the apparent and the mean solar time, **in days**.
+    pub equation_of_time: f64,
+    /// Distance between the Earth and the sun, in astronomical units.
+    pub radius_vector: f64,
+}
+
+/// An algorithm computing the apparent position of the sun, on which the computations of a
+/// [`SolarDay`] are based.
+///
+/// Times are given as Julian days in universal time: models which depend on the terrestrial time,
+/// such as [`Spa`], apply the difference ΔT themselves. See [`SolarDay`] for the implementations
+/// provided by this crate.
+///
+/// [`SolarDay`]: crate::SolarDay
+/// [`Spa`]: crate::Spa
+pub trait SolarModel {
+    /// Compute the apparent position of the sun at a given Julian day.
+    fn ephemeris(&self, day: JulianDay) -> Ephemeris;
+
+    /// Compute the Julian day of the solar transit of the day of a given mean solar noon.
+    fn transit(&self, mean_solar_noon: JulianDay) -> JulianDay {
+        JulianDay::new(mean_solar_noon.value() - self.ephemeris(mean_solar_noon).equation_of_time)
+    }
+
+    /// Compute the position of the sun at a given place and time.
+    fn sun_position(&self, coord: Coordinates, time: DateTime<Utc>) -> SolarPosition {
+        let day = JulianDay::from(time);
+        position(coord, day.value(), &self.ephemeris(day))
+    }
+}
@@ -0,0 +1,114 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use core::f64::consts::PI;
+
+use crate::julian::JulianDay;
+use crate::math::{asin, cos, rem_euclid, sin, tan};
+use crate::model::{Ephemeris, SolarModel};
+
+/// The algorithm of the NOAA solar calculator, based on the low accuracy formulas of Jean Meeus'
+/// "Astronomical Algorithms", see <https://gml.noaa.gov/grad/solcalc/calcdetails.html>.
+///
+/// It accounts for the nutation and aberration of the sun, and is more accurate than
+/// [`SunriseEquation`] away from the year 2000.
+///
+/// [`SunriseEquation`]: crate::SunriseEquation
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Noaa;
+
+impl SolarModel for Noaa {
+    fn ephemeris(&self, day: JulianDay) -> Ephemeris {
+        let t = day.centuries_since_j2000();
+
+        // Geometric mean longitude and anomaly of the sun, and eccentricity of the Earth orbit.
+        let mean_longitude = f64::to_radians(rem_euclid(
+            280.46646 + t * (36000.76983 + t * 0.0003032),
+            360.,
+        ));
+        let mean_anomaly = f64::to_radians(357.52911 + t * (35999.05029 - 0.0001537 * t));
+        let eccentricity = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+
+        let equation_of_center = f64::to_radians(
+            sin(mean_anomaly) * (1.914602 - t * (0.004817 + 0.000014 * t))
+                + sin(2. * mean_anomaly) * (0.019993 - 0.000101 * t)
+                + sin(3. * mean_anomaly) * 0.000289,
+        );
+        let true_longitude = mean_longitude + equation_of_center;
+        let true_anomaly = mean_anomaly + equation_of_center;
+        let radius_vector = 1.000001018 * (1. - eccentricity * eccentricity)
+            / (1. + eccentricity * cos(true_anomaly));
+
+        // Correct for nutation and aberration.
+        let omega = f64::to_radians(125.04 - 1934.136 * t);
+        let apparent_longitude = true_longitude - f64::to_radians(0.00569 + 0.00478 * sin(omega));
+        let mean_obliquity =
+            23. + (26. + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.) / 60.;
+        let obliquity = f64::to_radians(mean_obliquity + 0.00256 * cos(omega));
+
+        let y = tan(obliquity / 2.) * tan(obliquity / 2.);
+        let equation_of_time = y * sin(2. * mean_longitude) - 2. * eccentricity * sin(mean_anomaly)
+            + 4. * eccentricity * y * sin(mean_anomaly) * cos(2. * mean_longitude)
+            - 0.5 * y * y * sin(4. * mean_longitude)
+            - 1.25 * eccentricity * eccentricity * sin(2. * mean_anomaly);
+
+        Ephemeris {
+            declination: asin(sin(obliquity) * sin(apparent_longitude)),
+            equation_of_time: equation_of_time / (2. * PI),
+            radius_vector,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SunriseEquation;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_equation_of_time() {
+        // February 11, 2024: sundials are about 14 minutes late.
+        let ephemeris = Noaa.ephemeris(JulianDay::new(2460352.));
+        assert_relative_eq!(ephemeris.equation_of_time * 24. * 60., -14.2, epsilon = 0.1);
+    }
+
+    #[test]
+    fn test_matches_sunrise_equation() {
+        for day in [2451545., 2455000., 2460352., 2460500.] {
+            let day = JulianDay::new(day);
+            let noaa = Noaa.ephemeris(day);
+            let reference = SunriseEquation.ephemeris(day);
+            assert_relative_eq!(
+                noaa.declination,
+                reference.declination,
+                epsilon = f64::to_radians(0.2)
+            );
+            assert_relative_eq!(
+                noaa.equation_of_time,
+                reference.equation_of_time,
+                epsilon = 1. / (24. * 60.)
+            );
+            assert_relative_eq!(noaa.radius_vector, reference.radius_vector, epsilon = 0.001);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use chrono::{DateTime, Utc};
+
+use crate::Coordinates;
+use crate::event::{DawnType, SOLAR_SEMI_DIAMETER, SolarEvent};
+use crate::refraction::Refraction;
+use crate::solar_equation::sun_position;
+
+/// Phase of the sky depending on the elevation of the sun.
+///
+/// The thresholds are the same as the ones of [`SolarEvent::Sunrise`] and [`DawnType`]: a phase
+/// begins at the matching dawn or sunrise and ends at the matching dusk or sunset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SolarPhase {
+    /// The sun is above the horizon.
+    Day,
+    /// The sun is less than 6 degrees below the horizon.
+    CivilTwilight,
+    /// The sun is between 6 and 12 degrees below the horizon.
+    NauticalTwilight,
+    /// The sun is between 12 and 18 degrees below the horizon.
+    AstronomicalTwilight,
+    /// The sun is more than 18 degrees below the horizon.
+    Night,
+}
+
+impl SolarPhase {
+    /// Get the phase of the sky for a given elevation of the sun (**in radians**).
+    pub fn from_elevation(elevation: f64) -> Self {
+        let depression = -elevation;
+        let sunrise = SolarEvent::Sunrise
+            .angle(Refraction::Standard, SOLAR_SEMI_DIAMETER, 0.)
+            .expect("sunrise has an angle");
+
+        if depression <= sunrise {
+            SolarPhase::Day
+        } else if depression <= DawnType::Civil.positive_angle() {
+            SolarPhase::CivilTwilight
+        } else if depression <= DawnType::Nautical.positive_angle() {
+            SolarPhase::NauticalTwilight
+        } else if depression <= DawnType::Astronomical.positive_angle() {
+            SolarPhase::AstronomicalTwilight
+        } else {
+            SolarPhase::Night
+        }
+    }
+
+    /// `true` if the sun is above the horizon.
+    pub fn is_daylight(&self) -> bool {
+        *self == SolarPhase::Day
+    }
+}
+
+/// Get the phase of the sky at a given place and time.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use sunrise::{Coordinates, phase_at};
+///
+/// let coord = Coordinates::new(43.6532, -79.3832).unwrap();
+/// let time = Utc.with_ymd_and_hms(2016, 1, 1, 23, 0, 0).unwrap();
+///
+/// if !phase_at(coord, time).is_daylight() {
+///     println!("Turn on the lights!");
+/// }
+/// ```
+pub fn phase_at(coord: Coordinates, time: DateTime<Utc>) -> SolarPhase {
+    SolarPhase::from_elevation(sun_position(coord, time).elevation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_elevation() {
+        assert_eq!(SolarPhase::from_elevation(0.5), SolarPhase::Day);
+        assert_eq!(
+            SolarPhase::from_elevation(f64::to_radians(-0.5)),
+            SolarPhase::Day
+        );
+        assert_eq!(
+            SolarPhase::from_elevation(f64::to_radians(-3.)),
+            SolarPhase::CivilTwilight
+        );
+        assert_eq!(
+            SolarPhase::from_elevation(f64::to_radians(-6.)),
+            SolarPhase::CivilTwilight
+        );
+        assert_eq!(
+            SolarPhase::from_elevation(f64::to_radians(-10.)),
+            SolarPhase::NauticalTwilight
+        );
+        assert_eq!(
+            SolarPhase::from_elevation(f64::to_radians(-15.)),
+            SolarPhase::AstronomicalTwilight
+        );
+        assert_eq!(
+            SolarPhase::from_elevation(f64::to_radians(-40.)),
+            SolarPhase::Night
+        );
+    }
+
+    #[test]
+    fn test_phase_at() {
+        let coord = Coordinates::new(0., 0.).unwrap();
+        let noon = DateTime::from_timestamp(43412, 0).unwrap();
+        let midnight = DateTime::from_timestamp(212, 0).unwrap();
+        assert!(phase_at(coord, noon).is_daylight());
+        assert_eq!(phase_at(coord, midnight), SolarPhase::Night);
+    }
+}
@@ -0,0 +1,180 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use core::iter;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::event::{Occurrence, SolarEvent};
+use crate::model::SolarModel;
+use crate::solar_equation::SolarDay;
+
+/// Maximum number of days a polar period is followed in each direction, which bounds the search
+/// when the event never happens at all.
+const MAX_LENGTH: usize = 2 * 366;
+
+/// A range of consecutive days during which an event never happens, such as the midnight sun or
+/// the polar night, see [`polar_periods`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PolarPeriod {
+    /// First day of the period.
+    pub first: NaiveDate,
+    /// Last day of the period, included.
+    pub last: NaiveDate,
+    /// `true` if the sun stays above the elevation of the event (e.g., the midnight sun for
+    /// sunset), `false` if it stays below (e.g., the polar night for sunrise).
+    pub above: bool,
+}
+
+/// Iterate over the periods of a year during which `event` never happens in the solar days built
+/// by `solar_day`, in chronological order.
+///
+/// Using [`SolarEvent::Sunrise`] gives the midnight sun and the polar night, while
+/// [`SolarEvent::Dawn`] gives the variants of the polar night which are defined by the twilights,
+/// such as the civil polar night.
+///
+/// Periods which overlap the start or the end of the year are reported with their actual first
+/// and last days, as long as they last less than two years.
+///
+/// # Example
+///
+/// ```
+/// use sunrise::{Coordinates, SolarDay, SolarEvent, polar_periods};
+///
+/// // Tromsø
+/// let coord = Coordinates::new(69.6496, 18.956).unwrap();
+///
+/// for period in polar_periods(|date| SolarDay::new(coord, date), SolarEvent::Sunrise, 2024) {
+///     let name = if period.above { "midnight sun" } else { "polar night" };
+///     println!("{name} from {} to {}", period.first, period.last);
+/// }
+/// ```
+pub fn polar_periods<M: SolarModel>(
+    solar_day: impl Fn(NaiveDate) -> SolarDay<M>,
+    event: SolarEvent,
+    year: i32,
+) -> impl Iterator<Item = PolarPeriod> {
+    let always_above = move |date: NaiveDate| match solar_day(date).event_occurrence(event) {
+        Occurrence::Occurs(_) => None,
+        Occurrence::AlwaysAbove => Some(true),
+        Occurrence::AlwaysBelow => Some(false),
+    };
+
+    let mut next = NaiveDate::from_ymd_opt(year, 1, 1);
+
+    iter::from_fn(move || {
+        let mut date = next?;
+        let above = loop {
+            if let Some(above) = always_above(date) {
+                break above;
+            }
+            date = date.succ_opt().filter(|date| date.year() == year)?;
+        };
+
+        let same = |date: &NaiveDate| always_above(*date) == Some(above);
+        let first = if date.ordinal() == 1 {
+            last_of(date, NaiveDate::pred_opt, same)
+        } else {
+            date
+        };
+        let last = last_of(date, NaiveDate::succ_opt, same);
+
+        next = last.succ_opt().filter(|date| date.year() == year);
+        Some(PolarPeriod { first, last, above })
+    })
+}
+
+/// Follow consecutive days from `date` in a given direction, and get the last one which is still
+/// part of the period.
+fn last_of(
+    date: NaiveDate,
+    step: impl Fn(&NaiveDate) -> Option<NaiveDate>,
+    same: impl Fn(&NaiveDate) -> bool,
+) -> NaiveDate {
+    iter::successors(Some(date), step)
+        .take(MAX_LENGTH)
+        .take_while(same)
+        .last()
+        .unwrap_or(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Coordinates, DawnType};
+
+    #[test]
+    fn test_temperate() {
+        let coord = Coordinates::new(48.8566, 2.3522).unwrap();
+        let solar_day = |date| SolarDay::new(coord, date);
+        assert_eq!(
+            polar_periods(solar_day, SolarEvent::Sunrise, 2024).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_arctic() {
+        // Longyearbyen, Svalbard
+        let coord = Coordinates::new(78.2232, 15.6267).unwrap();
+        let solar_day = |date| SolarDay::new(coord, date);
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+
+        let mut periods = polar_periods(solar_day, SolarEvent::Sunrise, 2024);
+
+        // The polar night started during the previous year.
+        let night = periods.next().unwrap();
+        assert!(!night.above);
+        assert_eq!(night.first.year(), 2023);
+        assert!(night.last > date(2, 10) && night.last < date(2, 20));
+
+        let day = periods.next().unwrap();
+        assert!(day.above);
+        assert!(day.first > date(4, 15) && day.first < date(4, 25));
+        assert!(day.last > date(8, 20) && day.last < date(8, 30));
+
+        // The polar night continues during the next year.
+        let night = periods.next().unwrap();
+        assert!(!night.above);
+        assert!(night.first > date(10, 20) && night.first < date(10, 30));
+        assert_eq!(night.last.year(), 2025);
+
+        assert_eq!(periods.next(), None);
+    }
+
+    #[test]
+    fn test_civil_polar_night() {
+        let coord = Coordinates::new(78.2232, 15.6267).unwrap();
+        let solar_day = |date| SolarDay::new(coord, date);
+
+        let sunrise = polar_periods(solar_day, SolarEvent::Sunrise, 2024)
+            .find(|period| !period.above)
+            .unwrap();
+        let civil = polar_periods(solar_day, SolarEvent::Dawn(DawnType::Civil), 2024)
+            .find(|period| !period.above)
+            .unwrap();
+
+        // The civil polar night is shorter than the polar night.
+        assert!(civil.first > sunrise.first);
+        assert!(civil.last < sunrise.last);
+    }
+}
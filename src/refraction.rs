@@ -0,0 +1,308 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use crate::math::{acos, powf, tan};
+
+/// Pressure of the standard atmosphere used by the refraction formulas, in hPa.
+const STANDARD_PRESSURE: f64 = 1010.;
+
+/// Temperature of the standard atmosphere used by the refraction formulas, in °C.
+const STANDARD_TEMPERATURE: f64 = 10.;
+
+/// Mean radius of the Earth, in meters.
+pub(crate) const EARTH_RADIUS: f64 = 6371000.;
+
+/// Ratio between the radius of curvature of a horizontal ray of light and the radius of the
+/// Earth, which makes the horizon appear further away than it geometrically is.
+pub(crate) const TERRESTRIAL_REFRACTION: f64 = 0.13;
+
+/// Lowest apparent or geometric elevation at which the refraction formulas are used, in degrees.
+/// The sun cannot be seen below this elevation, so it is taken as geometric.
+const MIN_APPARENT_ELEVATION: f64 = -1.;
+
+/// Apparent elevation at which Bennett's formula is the largest, in degrees. Below it the formula
+/// decreases, which is not physical, so the refraction of a dipped horizon is held at this value.
+const MAX_REFRACTION_ELEVATION: f64 = -1.696;
+
+/// Model of the atmospheric refraction, which makes the sun appear higher in the sky than it
+/// geometrically is.
+///
+/// Refraction only applies to sunrise, sunset and [`SolarEvent::Elevation`], as dawn and dusk
+/// are defined by the geometric elevation of the sun.
+///
+/// [`SolarEvent::Elevation`]: crate::SolarEvent::Elevation
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Refraction {
+    /// No refraction: sunrise and sunset happen when the upper limb of the sun crosses the
+    /// geometric horizon.
+    None,
+    /// Conventional refraction of 34′ at the horizon used by almanacs, and refraction of the
+    /// standard atmosphere for other elevations.
+    #[default]
+    Standard,
+    /// Refraction of [`Refraction::Standard`], scaled for the conditions at the observer.
+    ///
+    /// Prefer [`Refraction::atmospheric`] which validates its input.
+    Atmospheric {
+        /// Atmospheric pressure, in hPa.
+        pressure: f64,
+        /// Air temperature, in °C.
+        temperature: f64,
+    },
+}
+
+impl Refraction {
+    /// Refraction for a given atmospheric pressure (in hPa) and air temperature (in °C).
+    ///
+    /// Return `None` if values are out of the physical range (negative pressure, or temperature
+    /// at or below -273 °C).
+    pub const fn atmospheric(pressure: f64, temperature: f64) -> Option<Self> {
+        if !pressure.is_finite()
+            || !temperature.is_finite()
+            || pressure < 0.
+            || temperature <= -273.
+        {
+            return None;
+        }
+
+        Some(Refraction::Atmospheric {
+            pressure,
+            temperature,
+        })
+    }
+
+    /// Get the geometric elevation of the sun when it is seen at a given apparent elevation, both
+    /// **in radians**. This uses Bennett's formula.
+    ///
+    /// With [`Refraction::Standard`] the formula is evaluated for a standard atmosphere.
+    pub fn true_elevation(&self, apparent_elevation: f64) -> f64 {
+        let h = apparent_elevation.to_degrees();
+        if h < MIN_APPARENT_ELEVATION {
+            return apparent_elevation;
+        }
+
+        apparent_elevation - self.scale() * f64::to_radians(bennett(h) / 60.)
+    }
+
+    /// Get the apparent elevation of the sun when it is at a given geometric elevation, both **in
+    /// radians**. This uses Sæmundsson's formula.
+    ///
+    /// With [`Refraction::Standard`] the formula is evaluated for a standard atmosphere.
+    pub fn apparent_elevation(&self, elevation: f64) -> f64 {
+        let h = elevation.to_degrees();
+        if h < MIN_APPARENT_ELEVATION {
+            return elevation;
+        }
+
+        let arcmin = 1.02 / tan(f64::to_radians(h + 10.3 / (h + 5.11)));
+        elevation + self.scale() * f64::to_radians(arcmin / 60.)
+    }
+
+    /// Refraction at a visible horizon which is `dip` below the astronomical horizon, in radians.
+    ///
+    /// This is the conventional 34′, scaled like Bennett's formula below the astronomical horizon
+    /// and for the atmospheric conditions, so that the standard atmosphere gives the same
+    /// refraction as [`Refraction::Standard`].
+    pub(crate) fn at_horizon(&self, dip: f64) -> f64 {
+        let h = f64::max(-dip.to_degrees(), MAX_REFRACTION_ELEVATION);
+        let arcmin = 34. * bennett(h) / bennett(0.) * self.scale();
+        f64::to_radians(arcmin / 60.)
+    }
+
+    /// Geometric elevation of a [`SolarEvent::Elevation`], in radians.
+    ///
+    /// [`SolarEvent::Elevation`]: crate::SolarEvent::Elevation
+    pub(crate) fn event_elevation(&self, elevation: f64) -> f64 {
+        self.true_elevation(elevation)
+    }
+
+    /// Refraction for an observer at a given elevation above the sea level, in meters.
+    ///
+    /// The [`Refraction::Standard`] model is replaced by the refraction of the standard
+    /// atmosphere at that elevation, other models are kept as they are.
+    pub(crate) fn at_elevation(self, elevation: f64) -> Self {
+        match self {
+            Refraction::Standard if elevation != 0. => Refraction::Atmospheric {
+                // The barometric formula reaches a null pressure at about 44 km.
+                pressure: STANDARD_PRESSURE * powf((1. - 2.25577e-5 * elevation).max(0.), 5.25588),
+                temperature: STANDARD_TEMPERATURE,
+            },
+            other => other,
+        }
+    }
+
+    /// Factor applied to the refraction for the atmospheric conditions.
+    fn scale(&self) -> f64 {
+        let (pressure, temperature) = match self {
+            Refraction::None => return 0.,
+            Refraction::Standard => (STANDARD_PRESSURE, STANDARD_TEMPERATURE),
+            Refraction::Atmospheric {
+                pressure,
+                temperature,
+            } => (*pressure, *temperature),
+        };
+
+        pressure / STANDARD_PRESSURE * 283. / (273. + temperature)
+    }
+}
+
+/// Refraction at a given apparent elevation (in degrees) according to Bennett's formula, in
+/// arcminutes.
+fn bennett(h: f64) -> f64 {
+    1. / tan(f64::to_radians(h + 7.31 / (h + 4.4)))
+}
+
+/// Dip of the visible horizon for an observer at a given height above it (in meters), in
+/// radians, accounting for the terrestrial refraction.
+///
+/// A negative height gives a raised horizon, as if the observer was below it.
+pub(crate) fn horizon_dip(height: f64) -> f64 {
+    let radius = EARTH_RADIUS / (1. - TERRESTRIAL_REFRACTION);
+    height.signum() * acos(radius / (radius + height.abs()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_horizon() {
+        assert_relative_eq!(Refraction::None.at_horizon(0.), 0.);
+        assert_relative_eq!(
+            Refraction::Standard.at_horizon(0.),
+            f64::to_radians(34. / 60.)
+        );
+        // The standard atmosphere keeps the conventional refraction.
+        assert_relative_eq!(
+            Refraction::Atmospheric {
+                pressure: 1010.,
+                temperature: 10.
+            }
+            .at_horizon(0.),
+            Refraction::Standard.at_horizon(0.)
+        );
+    }
+
+    #[test]
+    fn test_dipped_horizon() {
+        // The refraction grows below the astronomical horizon, up to about 57′.
+        let dip = horizon_dip(10000.);
+        let standard = Refraction::Standard.at_horizon(dip).to_degrees() * 60.;
+        assert!(standard > 50. && standard < 57.);
+        assert_relative_eq!(
+            Refraction::Standard
+                .at_horizon(f64::to_radians(5.))
+                .to_degrees()
+                * 60.,
+            standard
+        );
+
+        // Thin air at 10 km refracts about a third as much.
+        let thin = Refraction::Standard.at_elevation(10000.).at_horizon(dip);
+        assert!(thin > 0. && thin < Refraction::Standard.at_horizon(dip) / 2.);
+        assert_eq!(Refraction::None.at_horizon(dip), 0.);
+    }
+
+    #[test]
+    fn test_atmospheric() {
+        assert!(Refraction::atmospheric(-1., 10.).is_none());
+        assert!(Refraction::atmospheric(1010., -273.).is_none());
+        assert!(Refraction::atmospheric(f64::NAN, 10.).is_none());
+        assert!(Refraction::atmospheric(1010., f64::INFINITY).is_none());
+        assert_eq!(
+            Refraction::atmospheric(0., -40.),
+            Some(Refraction::Atmospheric {
+                pressure: 0.,
+                temperature: -40.
+            })
+        );
+    }
+
+    #[test]
+    fn test_cold_air() {
+        let cold = Refraction::Atmospheric {
+            pressure: 1030.,
+            temperature: -30.,
+        };
+        assert!(cold.at_horizon(0.) > Refraction::Standard.at_horizon(0.));
+    }
+
+    #[test]
+    fn test_inverse() {
+        for degrees in [0., 1., 5., 30.] {
+            let elevation = f64::to_radians(degrees);
+            let apparent = Refraction::Standard.apparent_elevation(elevation);
+            assert!(apparent > elevation);
+            assert_relative_eq!(
+                Refraction::Standard.true_elevation(apparent),
+                elevation,
+                epsilon = f64::to_radians(0.1 / 60.)
+            );
+        }
+    }
+
+    #[test]
+    fn test_below_horizon() {
+        let elevation = f64::to_radians(-10.);
+        assert_relative_eq!(Refraction::Standard.true_elevation(elevation), elevation);
+        assert_relative_eq!(
+            Refraction::Standard.apparent_elevation(elevation),
+            elevation
+        );
+
+        // Pole of Sæmundsson's formula.
+        let elevation = f64::to_radians(-5.11);
+        assert_relative_eq!(
+            Refraction::Standard.apparent_elevation(elevation),
+            elevation
+        );
+    }
+
+    #[test]
+    fn test_at_elevation() {
+        assert_eq!(Refraction::Standard.at_elevation(0.), Refraction::Standard);
+        assert_eq!(Refraction::None.at_elevation(1000.), Refraction::None);
+
+        let Refraction::Atmospheric { pressure, .. } = Refraction::Standard.at_elevation(1500.)
+        else {
+            panic!("expected an atmospheric model");
+        };
+        assert_relative_eq!(pressure, 842.8, epsilon = 0.1);
+
+        let Refraction::Atmospheric { pressure, .. } = Refraction::Standard.at_elevation(50000.)
+        else {
+            panic!("expected an atmospheric model");
+        };
+        assert_eq!(pressure, 0.);
+    }
+
+    #[test]
+    fn test_horizon_dip() {
+        assert_eq!(horizon_dip(0.), 0.);
+        // Almanacs give a dip of 1.76' × √h, with a slightly different terrestrial refraction.
+        assert_relative_eq!(horizon_dip(100.).to_degrees() * 60., 18., epsilon = 0.5);
+        assert_relative_eq!(horizon_dip(-100.), -horizon_dip(100.));
+        // The dip still holds from an aircraft.
+        assert_relative_eq!(horizon_dip(10000.).to_degrees(), 2.98, epsilon = 0.05);
+    }
+}
@@ -0,0 +1,80 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use chrono::{DateTime, Days, NaiveDate, Utc};
+
+use crate::event::SolarEvent;
+use crate::model::SolarModel;
+use crate::solar_equation::SolarDay;
+
+/// Find the first time strictly after `after` at which `event` happens, in the solar days built
+/// by `solar_day` for each date.
+///
+/// Since the events of a solar day may fall on the previous or next UTC date near the date line,
+/// the search always covers the solar days of the day before, the day of and the day after
+/// `after`, which hold the next event unless it does not happen. It then walks across up to
+/// `horizon` more days, which bounds the search during a polar day or night.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use sunrise::{Coordinates, SolarDay, SolarEvent, next_event};
+///
+/// let coord = Coordinates::new(43.6532, -79.3832).unwrap();
+/// let now = Utc.with_ymd_and_hms(2016, 1, 1, 23, 0, 0).unwrap();
+///
+/// // The sunrise of the next day, in the morning of January 2.
+/// let sunrise = next_event(|date| SolarDay::new(coord, date), SolarEvent::Sunrise, now, 0);
+/// assert!(sunrise.is_some());
+/// ```
+pub fn next_event<M: SolarModel>(
+    solar_day: impl Fn(NaiveDate) -> SolarDay<M>,
+    event: SolarEvent,
+    after: DateTime<Utc>,
+    horizon: u32,
+) -> Option<DateTime<Utc>> {
+    let first = after.date_naive().checked_sub_days(Days::new(1))?;
+
+    (0..=u64::from(horizon) + 2)
+        .map_while(|offset| first.checked_add_days(Days::new(offset)))
+        .filter_map(|date| solar_day(date).event_time(event))
+        .find(|time| *time > after)
+}
+
+/// Find the last time strictly before `before` at which `event` happens.
+///
+/// This is the counterpart of [`next_event`], which covers the solar days of the day after, the
+/// day of and the day before `before`, then walks backward across up to `horizon` more days.
+pub fn previous_event<M: SolarModel>(
+    solar_day: impl Fn(NaiveDate) -> SolarDay<M>,
+    event: SolarEvent,
+    before: DateTime<Utc>,
+    horizon: u32,
+) -> Option<DateTime<Utc>> {
+    let first = before.date_naive().checked_add_days(Days::new(1))?;
+
+    (0..=u64::from(horizon) + 2)
+        .map_while(|offset| first.checked_sub_days(Days::new(offset)))
+        .filter_map(|date| solar_day(date).event_time(event))
+        .find(|time| *time < before)
+}
@@ -22,10 +22,9 @@
 
 use core::f64::consts::PI;
 
+use crate::julian::J2000;
 use crate::math::rem_euclid;
 
-const J2000: f64 = 2451545.;
-
 /// Calculates the angle of the sun relative to the earth for the specified
 /// Julian day.
 pub(crate) fn solar_mean_anomaly(day: f64) -> f64 {
@@ -0,0 +1,64 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use core::f64::consts::PI;
+
+use crate::math::{asin, atan2, cos, rem_euclid, sin, tan};
+
+/// Elevation of the sun above the horizon, in radians.
+pub(crate) fn elevation(latitude_deg: f64, declination: f64, hour_angle: f64) -> f64 {
+    let latitude = latitude_deg.to_radians();
+    asin(sin(latitude) * sin(declination) + cos(latitude) * cos(declination) * cos(hour_angle))
+}
+
+/// Azimuth of the sun, in radians clockwise from true north.
+pub(crate) fn azimuth(latitude_deg: f64, declination: f64, hour_angle: f64) -> f64 {
+    let latitude = latitude_deg.to_radians();
+    let from_south = atan2(
+        sin(hour_angle),
+        cos(hour_angle) * sin(latitude) - tan(declination) * cos(latitude),
+    );
+    rem_euclid(from_south + PI, 2. * PI)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_noon() {
+        assert_relative_eq!(
+            elevation(45., f64::to_radians(10.), 0.),
+            f64::to_radians(55.),
+            epsilon = 0.00001
+        );
+        assert_relative_eq!(azimuth(45., f64::to_radians(10.), 0.), PI);
+        assert_relative_eq!(azimuth(-45., f64::to_radians(10.), 0.), 0.);
+    }
+
+    #[test]
+    fn test_equinox() {
+        assert_relative_eq!(azimuth(30., 0., -PI / 2.), PI / 2., epsilon = 0.00001);
+        assert_relative_eq!(azimuth(30., 0., PI / 2.), 3. * PI / 2., epsilon = 0.00001);
+    }
+}
@@ -20,46 +20,145 @@
 // FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
 // IN THE SOFTWARE.
 
-use crate::event::SolarEvent;
+use core::f64::consts::PI;
+
+use crate::event::{Occurrence, SolarEvent};
 use crate::math::{acos, cos, sin, sqrt};
+use crate::refraction::Refraction;
 
 /// Calculates the second of the two angles required to locate a point on the
 /// celestial sphere in the equatorial coordinate system.
+///
+/// If the cosine of the hour angle is out of range, the sun never reaches the
+/// elevation of the event: it stays below it when the cosine would be greater
+/// than 1 and above it when it would be lower than -1.
 pub(crate) fn hour_angle(
     latitude_deg: f64,
     declination: f64,
     altitude: f64,
+    refraction: Refraction,
+    limb: f64,
+    horizon_dip: f64,
     event: SolarEvent,
-) -> f64 {
-    let latitude = latitude_deg.to_radians();
-    let denominator = cos(latitude) * cos(declination);
+) -> Occurrence<f64> {
+    HourAngle::new(
+        latitude_deg,
+        declination,
+        altitude,
+        refraction,
+        limb,
+        horizon_dip,
+    )
+    .event(event)
+}
+
+/// Terms of the hour angle equation which are shared by all the events of a
+/// day, so that they are only computed once.
+pub(crate) struct HourAngle {
+    sin_product: f64,
+    cos_product: f64,
+    /// Approximate dip of the deprecated `with_altitude`, which applies to every event.
+    legacy_altitude_dip: f64,
+    refraction: Refraction,
+    limb: f64,
+    /// Refracted dip of the visible horizon, which only applies to sunrise and sunset.
+    horizon_dip: f64,
+}
+
+impl HourAngle {
+    pub(crate) fn new(
+        latitude_deg: f64,
+        declination: f64,
+        altitude: f64,
+        refraction: Refraction,
+        limb: f64,
+        horizon_dip: f64,
+    ) -> Self {
+        let latitude = latitude_deg.to_radians();
+
+        Self {
+            sin_product: sin(latitude) * sin(declination),
+            cos_product: cos(latitude) * cos(declination),
+            legacy_altitude_dip: f64::to_radians(2.076) * altitude.signum() * sqrt(altitude.abs())
+                / 60.,
+            refraction,
+            limb,
+            horizon_dip,
+        }
+    }
 
-    let numerator =
-        -sin(event.angle()
-            + (f64::to_radians(2.076) * altitude.signum() * sqrt(altitude.abs()) / 60.))
-            - sin(latitude) * sin(declination);
+    /// Calculates the hour angle of the input event, see [`hour_angle`].
+    pub(crate) fn event(&self, event: SolarEvent) -> Occurrence<f64> {
+        let angle = match event {
+            SolarEvent::Noon => return Occurrence::Occurs(0.),
+            SolarEvent::Midnight => return Occurrence::Occurs(-PI),
+            _ => event
+                .angle(self.refraction, self.limb, self.horizon_dip)
+                .expect("not a culmination"),
+        };
 
-    let sign = if event.is_morning() { -1. } else { 1. };
-    sign * acos(numerator / denominator)
+        let cos_hour_angle =
+            (-sin(angle + self.legacy_altitude_dip) - self.sin_product) / self.cos_product;
+        if cos_hour_angle > 1. || cos_hour_angle.is_nan() {
+            return Occurrence::AlwaysBelow;
+        }
+        if cos_hour_angle < -1. {
+            return Occurrence::AlwaysAbove;
+        }
+
+        let sign = if event.is_morning() { -1. } else { 1. };
+        Occurrence::Occurs(sign * acos(cos_hour_angle))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::event::SOLAR_SEMI_DIAMETER;
     use approx::assert_relative_eq;
 
     #[test]
     fn test_oposites() {
         assert_relative_eq!(
-            hour_angle(32., -22., 0., SolarEvent::Sunrise),
-            -hour_angle(32., -22., 0., SolarEvent::Sunset),
+            hour_angle(
+                32.,
+                -22.,
+                0.,
+                Refraction::Standard,
+                SOLAR_SEMI_DIAMETER,
+                0.,
+                SolarEvent::Sunrise
+            )
+            .occurs()
+            .unwrap(),
+            -hour_angle(
+                32.,
+                -22.,
+                0.,
+                Refraction::Standard,
+                SOLAR_SEMI_DIAMETER,
+                0.,
+                SolarEvent::Sunset
+            )
+            .occurs()
+            .unwrap(),
         );
     }
 
     #[test]
     fn test_prime_meridian() {
         assert_relative_eq!(
-            hour_angle(0., f64::to_radians(-22.97753), 0., SolarEvent::Sunset),
+            hour_angle(
+                0.,
+                f64::to_radians(-22.97753),
+                0.,
+                Refraction::Standard,
+                SOLAR_SEMI_DIAMETER,
+                0.,
+                SolarEvent::Sunset
+            )
+            .occurs()
+            .unwrap(),
             f64::to_radians(90.90516),
             epsilon = 0.00001
         );
@@ -68,15 +167,127 @@ mod tests {
     #[test]
     fn test_altitude() {
         assert_relative_eq!(
-            hour_angle(0., f64::to_radians(-22.97753), 100., SolarEvent::Sunset),
+            hour_angle(
+                0.,
+                f64::to_radians(-22.97753),
+                100.,
+                Refraction::Standard,
+                SOLAR_SEMI_DIAMETER,
+                0.,
+                SolarEvent::Sunset
+            )
+            .occurs()
+            .unwrap(),
             f64::to_radians(91.28098),
             epsilon = 0.00001
         );
 
         assert_relative_eq!(
-            hour_angle(0., f64::to_radians(-22.97753), -100., SolarEvent::Sunset),
+            hour_angle(
+                0.,
+                f64::to_radians(-22.97753),
+                -100.,
+                Refraction::Standard,
+                SOLAR_SEMI_DIAMETER,
+                0.,
+                SolarEvent::Sunset
+            )
+            .occurs()
+            .unwrap(),
             f64::to_radians(90.52933),
             epsilon = 0.00001
         );
     }
+
+    #[test]
+    fn test_culminations() {
+        assert_eq!(
+            hour_angle(
+                85.,
+                0.4,
+                0.,
+                Refraction::Standard,
+                SOLAR_SEMI_DIAMETER,
+                0.,
+                SolarEvent::Noon
+            ),
+            Occurrence::Occurs(0.)
+        );
+        assert_eq!(
+            hour_angle(
+                -85.,
+                0.4,
+                0.,
+                Refraction::Standard,
+                SOLAR_SEMI_DIAMETER,
+                0.,
+                SolarEvent::Midnight
+            ),
+            Occurrence::Occurs(-PI)
+        );
+    }
+
+    #[test]
+    fn test_polar() {
+        let declination = f64::to_radians(20.);
+        assert_eq!(
+            hour_angle(
+                85.,
+                declination,
+                0.,
+                Refraction::Standard,
+                SOLAR_SEMI_DIAMETER,
+                0.,
+                SolarEvent::Sunset
+            ),
+            Occurrence::AlwaysAbove
+        );
+        assert_eq!(
+            hour_angle(
+                -85.,
+                declination,
+                0.,
+                Refraction::Standard,
+                SOLAR_SEMI_DIAMETER,
+                0.,
+                SolarEvent::Sunrise
+            ),
+            Occurrence::AlwaysBelow
+        );
+    }
+
+    #[test]
+    fn test_horizon_dip() {
+        let dipped = HourAngle::new(
+            0.,
+            f64::to_radians(-22.97753),
+            0.,
+            Refraction::Standard,
+            SOLAR_SEMI_DIAMETER,
+            f64::to_radians(0.3),
+        );
+        // The sun is refracted by about 38′ on the dipped horizon, instead of 34′.
+        assert_relative_eq!(
+            dipped.event(SolarEvent::Sunset).occurs().unwrap(),
+            f64::to_radians(91.30512),
+            epsilon = 0.00001
+        );
+        assert_relative_eq!(
+            dipped
+                .event(SolarEvent::Dusk(crate::DawnType::Civil))
+                .occurs()
+                .unwrap(),
+            hour_angle(
+                0.,
+                f64::to_radians(-22.97753),
+                0.,
+                Refraction::Standard,
+                SOLAR_SEMI_DIAMETER,
+                0.,
+                SolarEvent::Dusk(crate::DawnType::Civil)
+            )
+            .occurs()
+            .unwrap()
+        );
+    }
 }
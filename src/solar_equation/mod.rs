@@ -23,25 +23,46 @@
 mod anomaly;
 mod center;
 mod declination;
+mod horizontal;
 mod hourangle;
 mod longitude;
+mod orbit;
 mod perihelion;
+mod position;
+mod season;
+mod solar_time;
 mod transit;
 
 use core::f64::consts::PI;
 
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, SubsecRound, TimeDelta, Timelike, Utc};
 
 use crate::Coordinates;
-use crate::event::SolarEvent;
-use crate::julian::{julian_to_unix, mean_solar_noon};
+use crate::event::{Culmination, Occurrence, SOLAR_SEMI_DIAMETER, SolarEvent};
+use crate::horizon::HorizonProfile;
+use crate::julian::{JulianDay, julian_to_datetime, mean_solar_noon};
+use crate::model::{Ephemeris, SolarModel};
+use crate::refraction::{Refraction, horizon_dip};
+use crate::timeline::Timeline;
 
 use self::anomaly::solar_mean_anomaly;
 use self::center::equation_of_center;
 use self::declination::declination;
-use self::hourangle::hour_angle;
+pub(crate) use self::horizontal::elevation;
+use self::hourangle::{HourAngle, hour_angle};
 use self::longitude::ecliptic_longitude;
-use self::transit::solar_transit;
+use self::orbit::{radius_vector, semi_diameter};
+use self::solar_time::days;
+
+pub(crate) use self::horizontal::azimuth;
+pub use self::orbit::{aphelion, perihelion, sun_distance, sun_semi_diameter};
+pub(crate) use self::position::position;
+pub use self::position::{SolarPosition, sun_position};
+pub use self::season::{Season, solar_longitude_time, solar_terms};
+pub use self::solar_time::{
+    equation_of_time, from_local_apparent_time, from_local_mean_time, local_apparent_time,
+    local_mean_time,
+};
 
 /// Represent a full day at specific location, which allows to compute the exact date & time of any
 /// solar event during this day.
@@ -50,62 +71,581 @@ use self::transit::solar_transit;
 ///
 /// ```
 /// use chrono::NaiveDate;
-/// use sunrise::{Coordinates, DawnType, SolarDay, SolarEvent};
+/// use sunrise::{Coordinates, SolarDay, SolarEvent};
 ///
 /// // January 1, 2016 in Toronto
 /// let date = NaiveDate::from_ymd_opt(2016, 1, 1).unwrap();
 /// let coord = Coordinates::new(43.6532, -79.3832).unwrap();
 ///
-/// let dawn = SolarDay::new(coord, date)
-///     .with_altitude(54.)
-///     .event_time(SolarEvent::Dawn(DawnType::Civil));
+/// // From the observation deck of the CN Tower, 346 meters above the ground, which is 76 meters
+/// // above the sea level.
+/// let sunrise = SolarDay::new(coord, date)
+///     .with_elevation(76.)
+///     .with_observer_height(346.)
+///     .event_time(SolarEvent::Sunrise);
 /// ```
+///
+/// The position of the sun is computed by a [`SolarModel`], which defaults to
+/// [`SunriseEquation`] and can be changed with [`SolarDay::with_model`].
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-pub struct SolarDay {
+pub struct SolarDay<M = SunriseEquation> {
+    model: M,
     lat: f64,
     altitude: f64,
+    observer_height: f64,
+    elevation: f64,
+    mean_solar_noon: f64,
     solar_transit: f64,
     declination: f64,
+    precision: Precision,
+    rounding: Rounding,
+    refraction: Refraction,
+    semi_diameter: f64,
+    limb: Option<Limb>,
+}
+
+/// How event times are computed by a [`SolarDay`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Precision {
+    /// Use the declination and solar transit of the mean solar noon for every event of the day.
+    ///
+    /// This is a single pass computation, which can be off by a few minutes at high latitudes.
+    #[default]
+    Fast,
+    /// Recompute the declination and solar transit at the time of each event and iterate until
+    /// the result converges.
+    Precise,
+}
+
+/// How event times returned by a [`SolarDay`] are rounded.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rounding {
+    /// Keep the full precision of the computation, down to the nanosecond.
+    #[default]
+    None,
+    /// Drop the fractional part of the seconds.
+    Truncate,
+    /// Round to the nearest second.
+    NearestSecond,
+    /// Round to the nearest minute, as almanacs such as the USNO tables do.
+    NearestMinute,
+}
+
+impl Rounding {
+    fn apply(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Rounding::None => time,
+            Rounding::Truncate => time.trunc_subsecs(0),
+            Rounding::NearestSecond => time.round_subsecs(0),
+            // `DurationRound` only supports the timestamps which fit in nanoseconds.
+            Rounding::NearestMinute => time
+                .checked_add_signed(TimeDelta::seconds(30))
+                .and_then(|time| time.with_second(0))
+                .and_then(|time| time.with_nanosecond(0))
+                .expect("invalid result"),
+        }
+    }
+}
+
+/// Part of the sun which defines sunrise and sunset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Limb {
+    /// The upper limb of the sun touches the horizon, which is the usual definition.
+    Upper,
+    /// The center of the disk of the sun is on the horizon.
+    Center,
+    /// The lower limb of the sun touches the horizon.
+    Lower,
+}
+
+/// The "complete calculation" of the sunrise equation, see
+/// <https://en.wikipedia.org/wiki/Sunrise_equation#Complete_calculation_on_Earth>.
+///
+/// This is the default model of a [`SolarDay`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SunriseEquation;
+
+impl SolarModel for SunriseEquation {
+    fn ephemeris(&self, day: JulianDay) -> Ephemeris {
+        let day = day.value();
+        let solar_anomaly = solar_mean_anomaly(day);
+        let equation_of_center = equation_of_center(solar_anomaly);
+        let ecliptic_longitude = ecliptic_longitude(solar_anomaly, equation_of_center, day);
+
+        Ephemeris {
+            declination: declination(ecliptic_longitude),
+            equation_of_time: transit::equation_of_time(solar_anomaly, ecliptic_longitude),
+            radius_vector: radius_vector(solar_anomaly),
+        }
+    }
 }
 
+/// Interval between the evaluations of the visibility of the sun above a horizon profile, in
+/// days.
+const VISIBILITY_STEP: f64 = 1. / 1440.;
+
+/// Maximum number of refinement steps performed in [`Precision::Precise`] mode.
+const MAX_ITERATIONS: usize = 10;
+
+/// Refinement stops once successive estimates are closer than this (in days, about 1ms).
+const CONVERGENCE_THRESHOLD: f64 = 1e-8;
+
 impl SolarDay {
     /// Initialize given position and a date.
     ///
     /// This will pre-compute some values so you should re-use this struct if it is possible.
     pub fn new(coord: Coordinates, date: NaiveDate) -> Self {
         let day = mean_solar_noon(coord.lon(), date);
-        let solar_anomaly = solar_mean_anomaly(day);
-        let equation_of_center = equation_of_center(solar_anomaly);
-        let ecliptic_longitude = ecliptic_longitude(solar_anomaly, equation_of_center, day);
-        let solar_transit = solar_transit(day, solar_anomaly, ecliptic_longitude);
-        let declination = declination(ecliptic_longitude);
+        let ephemeris = SunriseEquation.ephemeris(JulianDay::new(day));
 
         Self {
+            model: SunriseEquation,
             lat: coord.lat(),
             altitude: 0.,
-            solar_transit,
-            declination,
+            observer_height: 0.,
+            elevation: 0.,
+            mean_solar_noon: day,
+            solar_transit: SunriseEquation.transit(JulianDay::new(day)).value(),
+            declination: ephemeris.declination,
+            precision: Precision::Fast,
+            rounding: Rounding::None,
+            refraction: Refraction::Standard,
+            semi_diameter: semi_diameter(ephemeris.radius_vector),
+            limb: None,
+        }
+    }
+}
+
+impl<M: SolarModel> SolarDay<M> {
+    /// Specify the model used to compute the position of the sun. This defaults to
+    /// [`SunriseEquation`] if not specified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use sunrise::{Coordinates, Noaa, SolarDay, SolarEvent};
+    ///
+    /// // January 1, 2016 in Toronto
+    /// let date = NaiveDate::from_ymd_opt(2016, 1, 1).unwrap();
+    /// let coord = Coordinates::new(43.6532, -79.3832).unwrap();
+    ///
+    /// let sunrise = SolarDay::new(coord, date)
+    ///     .with_model(Noaa)
+    ///     .event_time(SolarEvent::Sunrise);
+    /// ```
+    pub fn with_model<N: SolarModel>(self, model: N) -> SolarDay<N> {
+        let mean_solar_noon = JulianDay::new(self.mean_solar_noon);
+        let ephemeris = model.ephemeris(mean_solar_noon);
+
+        SolarDay {
+            solar_transit: model.transit(mean_solar_noon).value(),
+            declination: ephemeris.declination,
+            semi_diameter: semi_diameter(ephemeris.radius_vector),
+            model,
+            lat: self.lat,
+            altitude: self.altitude,
+            observer_height: self.observer_height,
+            elevation: self.elevation,
+            mean_solar_noon: self.mean_solar_noon,
+            precision: self.precision,
+            rounding: self.rounding,
+            refraction: self.refraction,
+            limb: self.limb,
         }
     }
 
     /// Specify the altitude (in meters) of the observer, in meters. This defaults to 0 if not
     /// specified.
+    ///
+    /// This applies an approximate dip of the horizon to every event, including twilights. Use
+    /// [`SolarDay::with_observer_height`] and [`SolarDay::with_elevation`] instead.
+    #[deprecated(
+        since = "4.0.0",
+        note = "Use `with_observer_height` for the dip of the horizon and `with_elevation` for the \
+                elevation above the sea level."
+    )]
     pub fn with_altitude(mut self, altitude: f64) -> Self {
         self.altitude = altitude;
         self
     }
 
+    /// Specify the height (in meters) of the observer above the visible horizon, such as the
+    /// height of a tower above the sea. This defaults to 0 if not specified.
+    ///
+    /// Sunrise and sunset happen earlier and later from above, as the visible horizon dips below
+    /// the astronomical horizon. A negative height gives a raised horizon. Twilights and
+    /// [`SolarEvent::Elevation`] are relative to the astronomical horizon and are not affected.
+    pub fn with_observer_height(mut self, height: f64) -> Self {
+        self.observer_height = height;
+        self
+    }
+
+    /// Specify the elevation (in meters) of the observer above the sea level. This defaults to 0
+    /// if not specified.
+    ///
+    /// The lower air pressure reduces the refraction: with [`Refraction::Standard`], the
+    /// refraction of the standard atmosphere at this elevation is used instead. This does not
+    /// change the dip of the horizon, see [`SolarDay::with_observer_height`].
+    pub fn with_elevation(mut self, elevation: f64) -> Self {
+        self.elevation = elevation;
+        self
+    }
+
+    /// Specify how event times are computed. This defaults to [`Precision::Fast`] if not
+    /// specified.
+    pub fn with_precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Specify how event times are rounded. This defaults to [`Rounding::None`] if not specified,
+    /// which keeps sub-second precision.
+    pub fn with_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Specify the model of atmospheric refraction. This defaults to [`Refraction::Standard`] if
+    /// not specified.
+    pub fn with_refraction(mut self, refraction: Refraction) -> Self {
+        self.refraction = refraction;
+        self
+    }
+
+    /// Specify which part of the sun defines sunrise and sunset, using its apparent radius on
+    /// this day.
+    ///
+    /// If not specified, this uses the upper limb of the sun with its mean apparent radius, as
+    /// almanacs do.
+    pub fn with_limb(mut self, limb: Limb) -> Self {
+        self.limb = Some(limb);
+        self
+    }
+
     /// Get the time for when the input event will happen.
     ///
-    /// Returns `None` if the event does not happen (e.g., sunset in a polar day).
+    /// Returns `None` if the event does not happen (e.g., sunset in a polar day), see
+    /// [`SolarDay::event_occurrence`] to know why.
     pub fn event_time(&self, event: SolarEvent) -> Option<DateTime<Utc>> {
-        let hour_angle = hour_angle(self.lat, self.declination, self.altitude, event);
-        if hour_angle.is_nan() {
-            return None;
+        self.event_occurrence(event).occurs()
+    }
+
+    /// Get the time for when the input event will happen, or whether the sun stays above or below
+    /// the elevation of the event for the whole day if it does not happen.
+    pub fn event_occurrence(&self, event: SolarEvent) -> Occurrence {
+        self.event_julian(event).map(|day| self.datetime(day))
+    }
+
+    /// Get the azimuth of the sun when the input event happens, **in degrees** clockwise from true
+    /// north.
+    ///
+    /// Returns `None` if the event does not happen.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use sunrise::{Coordinates, SolarDay, SolarEvent};
+    ///
+    /// // June 21, 2016 in Oslo
+    /// let date = NaiveDate::from_ymd_opt(2016, 6, 21).unwrap();
+    /// let coord = Coordinates::new(59.9139, 10.7522).unwrap();
+    ///
+    /// // The sun rises in the north-east.
+    /// let azimuth = SolarDay::new(coord, date).event_azimuth(SolarEvent::Sunrise).unwrap();
+    /// assert!(azimuth > 30. && azimuth < 45.);
+    /// ```
+    pub fn event_azimuth(&self, event: SolarEvent) -> Option<f64> {
+        let day = self.event_julian(event).occurs()?;
+        let (solar_transit, declination) = match self.precision {
+            Precision::Fast => (self.solar_transit, self.declination),
+            Precision::Precise => {
+                let ephemeris = self.model.ephemeris(JulianDay::new(day));
+                (
+                    self.mean_solar_noon - ephemeris.equation_of_time,
+                    ephemeris.declination,
+                )
+            }
+        };
+
+        let hour_angle = 2. * PI * (day - solar_transit);
+        Some(azimuth(self.lat, declination, hour_angle).to_degrees())
+    }
+
+    /// Get all the standard transitions of the day in chronological order, from astronomical
+    /// dawn to astronomical dusk.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use sunrise::{Coordinates, SolarDay};
+    ///
+    /// // June 21, 2016 in Oslo
+    /// let date = NaiveDate::from_ymd_opt(2016, 6, 21).unwrap();
+    /// let coord = Coordinates::new(59.9139, 10.7522).unwrap();
+    ///
+    /// for (event, time) in SolarDay::new(coord, date).timeline().occurring() {
+    ///     println!("{event:?}: {time}");
+    /// }
+    /// ```
+    pub fn timeline(&self) -> Timeline {
+        match self.precision {
+            Precision::Fast => {
+                let hour_angle = HourAngle::new(
+                    self.lat,
+                    self.declination,
+                    self.altitude,
+                    self.refraction(),
+                    self.limb(),
+                    horizon_dip(self.observer_height),
+                );
+                Timeline::new(|event| {
+                    hour_angle.event(event).map(|hour_angle| {
+                        self.datetime(self.solar_transit + hour_angle / (2. * PI))
+                    })
+                })
+            }
+            Precision::Precise => Timeline::new(|event| self.event_occurrence(event)),
+        }
+    }
+
+    /// Get the time for when the sun first appears above a horizon profile, such as mountains
+    /// around a valley, or whether it stays hidden or visible for the whole day.
+    ///
+    /// The sun appears when its limb (see [`SolarDay::with_limb`]), raised by the refraction,
+    /// crosses the skyline. If it hides again behind a peak, this is still the time it first
+    /// appeared. If it never appears but hides during the day, it is reported as staying hidden,
+    /// as it does for the rest of the day.
+    pub fn visible_sunrise(&self, horizon: &HorizonProfile) -> Occurrence {
+        self.visible_event(horizon, true)
+    }
+
+    /// Get the time for when the sun last disappears below a horizon profile, or whether it stays
+    /// hidden or visible for the whole day, see [`SolarDay::visible_sunrise`].
+    ///
+    /// If the sun never disappears but appears during the day, it is reported as staying visible.
+    pub fn visible_sunset(&self, horizon: &HorizonProfile) -> Occurrence {
+        self.visible_event(horizon, false)
+    }
+
+    /// Get the time and elevation of the sun at solar noon.
+    pub fn noon(&self) -> Culmination {
+        self.culmination(SolarEvent::Noon, 0.)
+    }
+
+    /// Get the time and elevation of the sun at the solar midnight preceding solar noon.
+    pub fn midnight(&self) -> Culmination {
+        self.culmination(SolarEvent::Midnight, -PI)
+    }
+
+    /// Get the time between sunrise and sunset, which is 24 hours during the midnight sun and
+    /// zero during the polar night.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use sunrise::{Coordinates, SolarDay};
+    ///
+    /// // June 21, 2016 in Oslo
+    /// let date = NaiveDate::from_ymd_opt(2016, 6, 21).unwrap();
+    /// let coord = Coordinates::new(59.9139, 10.7522).unwrap();
+    ///
+    /// let day_length = SolarDay::new(coord, date).day_length();
+    /// assert_eq!(day_length.num_hours(), 18);
+    /// ```
+    pub fn day_length(&self) -> TimeDelta {
+        let sunrise = self.event_julian(SolarEvent::Sunrise);
+        let sunset = self.event_julian(SolarEvent::Sunset);
+        let culmination = |event| {
+            self.event_julian(event)
+                .occurs()
+                .expect("culminations always occur")
+        };
+
+        // With precise computations, the declination of the sun changes between sunrise and
+        // sunset, so only one of them may happen on the first or last day of the midnight sun or
+        // of the polar night.
+        match (sunrise, sunset) {
+            (Occurrence::Occurs(sunrise), Occurrence::Occurs(sunset)) => days(sunset - sunrise),
+            // The sun stays up since the previous solar midnight or until the next one.
+            (Occurrence::AlwaysAbove, Occurrence::Occurs(sunset)) => {
+                days(sunset - culmination(SolarEvent::Midnight))
+            }
+            (Occurrence::Occurs(sunrise), Occurrence::AlwaysAbove) => {
+                days(culmination(SolarEvent::Midnight) + 1. - sunrise)
+            }
+            // The sun only shows up around solar noon.
+            (Occurrence::AlwaysBelow, Occurrence::Occurs(sunset)) => {
+                days(2. * (sunset - culmination(SolarEvent::Noon)).max(0.))
+            }
+            (Occurrence::Occurs(sunrise), Occurrence::AlwaysBelow) => {
+                days(2. * (culmination(SolarEvent::Noon) - sunrise).max(0.))
+            }
+            (Occurrence::AlwaysAbove, _) | (_, Occurrence::AlwaysAbove) => TimeDelta::days(1),
+            _ => TimeDelta::zero(),
+        }
+    }
+
+    /// Get the Julian day of the mean solar noon, which the time of day of the events is relative
+    /// to.
+    pub(crate) fn mean_solar_noon(&self) -> f64 {
+        self.mean_solar_noon
+    }
+
+    fn culmination(&self, event: SolarEvent, hour_angle: f64) -> Culmination {
+        let day = self
+            .event_julian(event)
+            .occurs()
+            .expect("culminations always occur");
+
+        Culmination {
+            time: self.datetime(day),
+            elevation: elevation(self.lat, self.declination_at(day), hour_angle),
         }
+    }
+
+    /// Calculates the signed angle between the zenith and the sun at solar noon, which is
+    /// positive when the sun culminates south of the zenith.
+    pub(crate) fn noon_zenith_angle(&self) -> f64 {
+        let day = self
+            .event_julian(SolarEvent::Noon)
+            .occurs()
+            .expect("culminations always occur");
+        self.lat.to_radians() - self.declination_at(day)
+    }
+
+    /// Calculates the declination of the sun at a given Julian day of the solar day.
+    fn declination_at(&self, day: f64) -> f64 {
+        match self.precision {
+            Precision::Fast => self.declination,
+            Precision::Precise => self.model.ephemeris(JulianDay::new(day)).declination,
+        }
+    }
+
+    /// Get the Julian day at which the input event will happen.
+    fn event_julian(&self, event: SolarEvent) -> Occurrence<f64> {
+        let mut day = match self.event_julian_with(self.solar_transit, self.declination, event) {
+            Occurrence::Occurs(day) => day,
+            other => return other,
+        };
+
+        if self.precision == Precision::Precise {
+            for _ in 0..MAX_ITERATIONS {
+                let ephemeris = self.model.ephemeris(JulianDay::new(day));
+                let solar_transit = self.mean_solar_noon - ephemeris.equation_of_time;
+                let next = match self.event_julian_with(solar_transit, ephemeris.declination, event)
+                {
+                    Occurrence::Occurs(next) => next,
+                    other => return other,
+                };
+                let delta = next - day;
+                day = next;
+
+                if delta.abs() < CONVERGENCE_THRESHOLD {
+                    break;
+                }
+            }
+        }
+
+        Occurrence::Occurs(day)
+    }
+
+    fn event_julian_with(
+        &self,
+        solar_transit: f64,
+        declination: f64,
+        event: SolarEvent,
+    ) -> Occurrence<f64> {
+        hour_angle(
+            self.lat,
+            declination,
+            self.altitude,
+            self.refraction(),
+            self.limb(),
+            horizon_dip(self.observer_height),
+            event,
+        )
+        .map(|hour_angle| solar_transit + hour_angle / (2. * PI))
+    }
+
+    /// Scans the day from solar midnight to solar midnight for the first or last crossing of the
+    /// skyline, and refines it by bisection.
+    fn visible_event(&self, horizon: &HorizonProfile, morning: bool) -> Occurrence {
+        let start = self.solar_transit - 0.5;
+        let steps = (1. / VISIBILITY_STEP) as usize;
+        let visible = |day: f64| self.visibility(horizon, day) > 0.;
+
+        let mut crossing = None;
+        let mut previous = visible(start);
+
+        for step in 1..=steps {
+            let day = start + step as f64 * VISIBILITY_STEP;
+            let current = visible(day);
+
+            if current != previous && current == morning {
+                crossing = Some(day - VISIBILITY_STEP);
+                if morning {
+                    break;
+                }
+            }
+            previous = current;
+        }
+
+        // Without the requested crossing, the sun either never crosses the skyline or only
+        // crosses it the other way, and it then stays on the side it ends the day on.
+        let Some(mut low) = crossing else {
+            return if previous {
+                Occurrence::AlwaysAbove
+            } else {
+                Occurrence::AlwaysBelow
+            };
+        };
+
+        let mut high = low + VISIBILITY_STEP;
+        while high - low > CONVERGENCE_THRESHOLD {
+            let middle = (low + high) / 2.;
+            if visible(middle) == morning {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+
+        Occurrence::Occurs(self.datetime((low + high) / 2.))
+    }
+
+    /// Calculates the apparent elevation of the limb of the sun above the skyline at a given
+    /// Julian day, in radians.
+    fn visibility(&self, horizon: &HorizonProfile, day: f64) -> f64 {
+        let ephemeris = self.model.ephemeris(JulianDay::new(day));
+        let hour_angle = 2. * PI * (day - self.mean_solar_noon + ephemeris.equation_of_time);
+        let elevation = elevation(self.lat, ephemeris.declination, hour_angle);
+        let azimuth = azimuth(self.lat, ephemeris.declination, hour_angle);
+
+        self.refraction().apparent_elevation(elevation) + self.limb() - horizon.altitude(azimuth)
+    }
+
+    /// Refraction model at the elevation of the observer.
+    fn refraction(&self) -> Refraction {
+        self.refraction.at_elevation(self.elevation)
+    }
+
+    /// Elevation of the limb of the sun defining sunrise and sunset relative to its center.
+    fn limb(&self) -> f64 {
+        match self.limb {
+            None => SOLAR_SEMI_DIAMETER,
+            Some(Limb::Upper) => self.semi_diameter,
+            Some(Limb::Center) => 0.,
+            Some(Limb::Lower) => -self.semi_diameter,
+        }
+    }
 
-        let frac = hour_angle / (2. * PI);
-        let timestamp = julian_to_unix(self.solar_transit + frac);
-        Some(DateTime::from_timestamp(timestamp, 0).expect("invalid result"))
+    /// Convert a Julian day to a time rounded as configured.
+    fn datetime(&self, day: f64) -> DateTime<Utc> {
+        let time = julian_to_datetime(day).expect("invalid result");
+        self.rounding.apply(time)
     }
 }
@@ -0,0 +1,117 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+use crate::julian::{datetime_to_julian, julian_to_datetime};
+use crate::math::{cos, rem_euclid};
+
+use super::anomaly::solar_mean_anomaly;
+
+/// Apparent radius of the sun at a distance of 1 AU, in arcseconds.
+const SEMI_DIAMETER_AT_1_AU: f64 = 959.63;
+
+/// Daily motion of the mean anomaly, in degrees.
+const MEAN_ANOMALY_RATE: f64 = 0.98560028;
+
+/// Distance between the Earth and the sun, in astronomical units.
+pub(crate) fn radius_vector(solar_anomaly: f64) -> f64 {
+    1.00014 - 0.01671 * cos(solar_anomaly) - 0.00014 * cos(2. * solar_anomaly)
+}
+
+/// Apparent radius of the sun seen from a given distance (in astronomical units), in radians.
+pub(crate) fn semi_diameter(radius_vector: f64) -> f64 {
+    f64::to_radians(SEMI_DIAMETER_AT_1_AU / 3600.) / radius_vector
+}
+
+/// Get the distance between the Earth and the sun at a given instant, in astronomical units.
+pub fn sun_distance(time: DateTime<Utc>) -> f64 {
+    radius_vector(solar_mean_anomaly(datetime_to_julian(time)))
+}
+
+/// Get the apparent radius of the sun at a given instant, **in radians**.
+pub fn sun_semi_diameter(time: DateTime<Utc>) -> f64 {
+    semi_diameter(sun_distance(time))
+}
+
+/// Get the instant of the perihelion of a given year, when the Earth is the closest to the sun.
+///
+/// This is the instant when the mean anomaly of the sun is zero, which may be off by a day or two
+/// from the actual perihelion since the perturbations of the Moon are not modelled.
+pub fn perihelion(year: i32) -> Option<DateTime<Utc>> {
+    mean_anomaly_time(year, 0.)
+}
+
+/// Get the instant of the aphelion of a given year, when the Earth is the farthest from the sun.
+///
+/// See [`perihelion`] for the precision of the result.
+pub fn aphelion(year: i32) -> Option<DateTime<Utc>> {
+    mean_anomaly_time(year, 180.)
+}
+
+/// Get the first instant of a year at which the mean anomaly of the sun has a given value (in
+/// degrees).
+fn mean_anomaly_time(year: i32, anomaly: f64) -> Option<DateTime<Utc>> {
+    let start = NaiveDate::from_ymd_opt(year, 1, 1)?
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+    let start = datetime_to_julian(start);
+    let start_anomaly = solar_mean_anomaly(start).to_degrees();
+    julian_to_datetime(start + rem_euclid(anomaly - start_anomaly, 360.) / MEAN_ANOMALY_RATE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use chrono::{Datelike, TimeZone};
+
+    #[test]
+    fn test_radius_vector() {
+        assert_relative_eq!(radius_vector(0.), 0.98329, epsilon = 0.00001);
+        assert_relative_eq!(
+            radius_vector(core::f64::consts::PI),
+            1.01671,
+            epsilon = 0.00001
+        );
+    }
+
+    #[test]
+    fn test_semi_diameter() {
+        assert_relative_eq!(semi_diameter(1.).to_degrees() * 60., 15.99, epsilon = 0.01);
+        let january = Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap();
+        let july = Utc.with_ymd_and_hms(2024, 7, 5, 0, 0, 0).unwrap();
+        assert!(sun_semi_diameter(january) > sun_semi_diameter(july));
+    }
+
+    #[test]
+    fn test_apsides() {
+        let perihelion = perihelion(2024).unwrap();
+        assert_eq!(perihelion.month(), 1);
+        assert!((2..=4).contains(&perihelion.day()));
+        assert_relative_eq!(sun_distance(perihelion), 0.98329, epsilon = 0.00001);
+
+        let aphelion = aphelion(2024).unwrap();
+        assert_eq!(aphelion.month(), 7);
+        assert!((3..=6).contains(&aphelion.day()));
+    }
+}
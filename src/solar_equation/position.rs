@@ -0,0 +1,114 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use core::f64::consts::PI;
+
+use chrono::{DateTime, Utc};
+
+use crate::Coordinates;
+use crate::julian::J2000;
+use crate::math::rem_euclid;
+use crate::model::{Ephemeris, SolarModel};
+
+use super::SunriseEquation;
+use super::horizontal::{azimuth, elevation};
+
+/// Position of the sun in the sky of an observer at a given instant.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct SolarPosition {
+    /// Geometric elevation of the center of the sun above the horizon, **in radians**.
+    pub elevation: f64,
+    /// Azimuth of the sun clockwise from true north, **in radians**.
+    pub azimuth: f64,
+    /// Hour angle of the sun, **in radians**: negative before solar noon and positive after.
+    pub hour_angle: f64,
+    /// Declination of the sun, **in radians**.
+    pub declination: f64,
+}
+
+/// Compute the position of the sun at a given place and time.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use sunrise::{Coordinates, sun_position};
+///
+/// let coord = Coordinates::new(43.6532, -79.3832).unwrap();
+/// let position = sun_position(coord, Utc.with_ymd_and_hms(2016, 6, 1, 17, 0, 0).unwrap());
+/// assert!(position.elevation > 0.);
+/// ```
+pub fn sun_position(coord: Coordinates, time: DateTime<Utc>) -> SolarPosition {
+    SunriseEquation.sun_position(coord, time)
+}
+
+/// Calculates the position of the sun at a given place and Julian day, from
+/// the ephemeris of the sun at that day.
+pub(crate) fn position(coord: Coordinates, day: f64, ephemeris: &Ephemeris) -> SolarPosition {
+    // The sun crosses the meridian when the apparent solar time is noon.
+    let hour_angle = rem_euclid(
+        2. * PI * (day - J2000 + coord.lon() / 360. + ephemeris.equation_of_time) + PI,
+        2. * PI,
+    ) - PI;
+
+    SolarPosition {
+        elevation: elevation(coord.lat(), ephemeris.declination, hour_angle),
+        azimuth: azimuth(coord.lat(), ephemeris.declination, hour_angle),
+        hour_angle,
+        declination: ephemeris.declination,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_prime_meridian() {
+        let position = sun_position(
+            Coordinates::new(0., 0.).unwrap(),
+            DateTime::from_timestamp(43412, 0).unwrap(),
+        );
+        assert_relative_eq!(position.hour_angle, 0., epsilon = 0.001);
+        assert_relative_eq!(
+            position.declination,
+            f64::to_radians(-22.97753),
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(position.azimuth, PI, epsilon = 0.001);
+    }
+
+    #[test]
+    fn test_sunrise() {
+        let position = sun_position(
+            Coordinates::new(0., 0.).unwrap(),
+            DateTime::from_timestamp(21594, 0).unwrap(),
+        );
+        assert_relative_eq!(
+            position.elevation,
+            -f64::to_radians(5.) / 6.,
+            epsilon = 0.001
+        );
+        assert!(position.azimuth > PI / 2. && position.azimuth < PI);
+    }
+}
@@ -0,0 +1,187 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+use crate::julian::{datetime_to_julian, julian_to_datetime};
+use crate::math::rem_euclid;
+use crate::spa::Spa;
+use crate::timescale::DeltaT;
+
+/// Mean daily motion of the ecliptic longitude of the sun, in degrees.
+const LONGITUDE_RATE: f64 = 360. / 365.2422;
+
+/// Difference of longitude (in degrees) below which the root finding stops, about a second.
+const LONGITUDE_THRESHOLD: f64 = 1e-5;
+
+/// Maximum number of refinements of the instant of a solar longitude.
+const MAX_ITERATIONS: usize = 10;
+
+/// Longitude (in degrees) of the first solar term of a year, the minor cold around January 5.
+const FIRST_SOLAR_TERM: f64 = 285.;
+
+/// One of the four instants of the year at which the sun crosses the equator or reaches its
+/// northernmost or southernmost declination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+    /// The sun crosses the equator northward, around March 20.
+    MarchEquinox,
+    /// The sun reaches its northernmost declination, around June 21.
+    JuneSolstice,
+    /// The sun crosses the equator southward, around September 22.
+    SeptemberEquinox,
+    /// The sun reaches its southernmost declination, around December 21.
+    DecemberSolstice,
+}
+
+impl Season {
+    /// Get the ecliptic longitude of the sun at this instant, **in degrees**.
+    pub fn longitude(self) -> f64 {
+        match self {
+            Season::MarchEquinox => 0.,
+            Season::JuneSolstice => 90.,
+            Season::SeptemberEquinox => 180.,
+            Season::DecemberSolstice => 270.,
+        }
+    }
+
+    /// Get the instant of this equinox or solstice during a given year.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, Timelike};
+    /// use sunrise::Season;
+    ///
+    /// let equinox = Season::MarchEquinox.time(2024).unwrap();
+    /// assert_eq!((equinox.month(), equinox.day(), equinox.hour()), (3, 20, 3));
+    /// ```
+    pub fn time(self, year: i32) -> Option<DateTime<Utc>> {
+        solar_longitude_time(year, self.longitude())
+    }
+}
+
+/// Get the first instant of a year at which the ecliptic longitude of the sun reaches a given
+/// value, **in degrees**.
+///
+/// Multiples of 90° give the equinoxes and solstices, odd multiples of 45° give the cross-quarter
+/// days, and multiples of 15° give the solar terms, see [`solar_terms`].
+///
+/// This is the apparent longitude of [`Spa`], with the default [`DeltaT`] of the year, which makes
+/// the result accurate to a few seconds as long as ΔT is well predicted.
+pub fn solar_longitude_time(year: i32, longitude: f64) -> Option<DateTime<Utc>> {
+    let start = NaiveDate::from_ymd_opt(year, 1, 1)?
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+    let spa = Spa::new(DeltaT::default().at(start));
+    let sun_longitude = |day| sun_longitude(&spa, day);
+    let start = datetime_to_julian(start);
+
+    // Start from the mean motion of the sun, then refine with Newton's method.
+    let mut day = start + rem_euclid(longitude - sun_longitude(start), 360.) / LONGITUDE_RATE;
+    for _ in 0..MAX_ITERATIONS {
+        let delta = rem_euclid(longitude - sun_longitude(day) + 180., 360.) - 180.;
+        day += delta / LONGITUDE_RATE;
+
+        if delta.abs() < LONGITUDE_THRESHOLD {
+            break;
+        }
+    }
+
+    julian_to_datetime(day)
+}
+
+/// Get the instants of the 24 solar terms of a given year in chronological order, when the
+/// ecliptic longitude of the sun is a multiple of 15°, starting with the minor cold at 285°.
+///
+/// # Example
+///
+/// ```
+/// use chrono::Datelike;
+/// use sunrise::solar_terms;
+///
+/// let terms: Vec<_> = solar_terms(2024).collect();
+/// assert_eq!(terms.len(), 24);
+/// assert_eq!((terms[0].month(), terms[0].day()), (1, 5));
+/// ```
+pub fn solar_terms(year: i32) -> impl Iterator<Item = DateTime<Utc>> {
+    (0..24).filter_map(move |term| {
+        solar_longitude_time(year, rem_euclid(FIRST_SOLAR_TERM + 15. * term as f64, 360.))
+    })
+}
+
+/// Calculates the apparent ecliptic longitude of the sun at a given Julian day, in degrees,
+/// measured from the equinox of the date.
+fn sun_longitude(spa: &Spa, day: f64) -> f64 {
+    spa.geocentric(day).apparent_longitude.to_degrees()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_seasons() {
+        // Instants published by the US Naval Observatory.
+        let expected = [
+            (Season::MarchEquinox, (3, 20, 3, 6)),
+            (Season::JuneSolstice, (6, 20, 20, 51)),
+            (Season::SeptemberEquinox, (9, 22, 12, 44)),
+            (Season::DecemberSolstice, (12, 21, 9, 20)),
+        ];
+
+        for (season, (month, day, hour, minute)) in expected {
+            let expected = Utc
+                .with_ymd_and_hms(2024, month, day, hour, minute, 0)
+                .unwrap();
+            let time = season.time(2024).unwrap();
+            assert!(
+                (time - expected).num_seconds().abs() <= 60,
+                "{season:?}: {time}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_solar_longitude_time() {
+        let time = solar_longitude_time(2024, 45.).unwrap();
+        let spa = Spa::new(69.);
+        assert_relative_eq!(
+            rem_euclid(sun_longitude(&spa, datetime_to_julian(time)), 360.),
+            45.,
+            epsilon = 1e-3
+        );
+    }
+
+    #[test]
+    fn test_solar_terms() {
+        let mut terms = solar_terms(2024);
+        let mut previous = terms.next().unwrap();
+        for time in terms {
+            let days = (time - previous).num_hours() as f64 / 24.;
+            assert!((14.5..=16.).contains(&days), "{time}");
+            previous = time;
+        }
+    }
+}
@@ -0,0 +1,137 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+
+use crate::Coordinates;
+use crate::julian::JulianDay;
+use crate::model::SolarModel;
+
+use super::SunriseEquation;
+
+/// Number of refinement steps when converting from apparent solar time. The equation of time
+/// varies by less than a minute per day, so this converges quickly.
+const ITERATIONS: usize = 3;
+
+/// Get the equation of time at a given instant: the difference between the apparent solar time,
+/// as shown by a sundial, and the mean solar time.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use sunrise::equation_of_time;
+///
+/// // Sundials are about 16 minutes ahead early November.
+/// let eot = equation_of_time(Utc.with_ymd_and_hms(2024, 11, 3, 12, 0, 0).unwrap());
+/// assert_eq!(eot.num_minutes(), 16);
+/// ```
+pub fn equation_of_time(time: DateTime<Utc>) -> TimeDelta {
+    days(
+        SunriseEquation
+            .ephemeris(JulianDay::from(time))
+            .equation_of_time,
+    )
+}
+
+/// Get the local mean solar time at a given place and instant, which is noon when the mean sun
+/// crosses the meridian.
+pub fn local_mean_time(coord: Coordinates, time: DateTime<Utc>) -> NaiveDateTime {
+    time.naive_utc() + longitude_offset(coord)
+}
+
+/// Get the local apparent solar time at a given place and instant, which is noon when the sun
+/// crosses the meridian.
+pub fn local_apparent_time(coord: Coordinates, time: DateTime<Utc>) -> NaiveDateTime {
+    local_mean_time(coord, time) + equation_of_time(time)
+}
+
+/// Get the instant at which the local mean solar time of a given place has a given value.
+///
+/// This is the inverse of [`local_mean_time`].
+pub fn from_local_mean_time(coord: Coordinates, time: NaiveDateTime) -> DateTime<Utc> {
+    (time - longitude_offset(coord)).and_utc()
+}
+
+/// Get the instant at which the local apparent solar time of a given place has a given value.
+///
+/// This is the inverse of [`local_apparent_time`].
+pub fn from_local_apparent_time(coord: Coordinates, time: NaiveDateTime) -> DateTime<Utc> {
+    let mean = from_local_mean_time(coord, time);
+    let mut result = mean;
+
+    for _ in 0..ITERATIONS {
+        result = mean - equation_of_time(result);
+    }
+
+    result
+}
+
+/// Difference between the local mean time and UTC at the longitude of a place.
+fn longitude_offset(coord: Coordinates) -> TimeDelta {
+    days(coord.lon() / 360.)
+}
+
+/// Convert a number of days to a duration, with nanosecond precision.
+pub(crate) fn days(days: f64) -> TimeDelta {
+    TimeDelta::nanoseconds((days * 86_400e9) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_equation_of_time() {
+        let february = Utc.with_ymd_and_hms(2024, 2, 11, 12, 0, 0).unwrap();
+        assert_eq!(equation_of_time(february).num_minutes(), -14);
+    }
+
+    #[test]
+    fn test_local_mean_time() {
+        let coord = Coordinates::new(0., 90.).unwrap();
+        let time = Utc.with_ymd_and_hms(2024, 2, 11, 6, 0, 0).unwrap();
+        assert_eq!(
+            local_mean_time(coord, time),
+            time.naive_utc() + TimeDelta::hours(6)
+        );
+        assert_eq!(
+            from_local_mean_time(coord, local_mean_time(coord, time)),
+            time
+        );
+    }
+
+    #[test]
+    fn test_local_apparent_time() {
+        let coord = Coordinates::new(45., -73.5).unwrap();
+        let time = Utc.with_ymd_and_hms(2024, 11, 3, 17, 0, 0).unwrap();
+        let apparent = local_apparent_time(coord, time);
+        assert_eq!(
+            apparent - local_mean_time(coord, time),
+            equation_of_time(time)
+        );
+
+        let back = from_local_apparent_time(coord, apparent);
+        assert!((back - time).abs() < TimeDelta::milliseconds(1));
+    }
+}
@@ -22,9 +22,10 @@
 
 use crate::math::sin;
 
-/// Calculates the Julian day for the local true solar transit.
-pub(crate) fn solar_transit(day: f64, solar_anomaly: f64, ecliptic_longitude: f64) -> f64 {
-    day + (0.0053 * sin(solar_anomaly) - 0.0069 * sin(2. * ecliptic_longitude))
+/// Calculates the difference between the apparent and the mean solar time, in
+/// days. The true solar transit happens that much before the mean solar noon.
+pub(crate) fn equation_of_time(solar_anomaly: f64, ecliptic_longitude: f64) -> f64 {
+    -0.0053 * sin(solar_anomaly) + 0.0069 * sin(2. * ecliptic_longitude)
 }
 
 #[cfg(test)]
@@ -33,14 +34,22 @@ mod tests {
 
     #[test]
     fn test_prime_meridian() {
+        // The true solar transit happens the equation of time before the mean solar noon.
         assert_relative_eq!(
-            super::solar_transit(
-                2440588.,
-                f64::to_radians(358.30683),
-                f64::to_radians(281.08372)
-            ),
+            2440588.
+                - super::equation_of_time(f64::to_radians(358.30683), f64::to_radians(281.08372)),
             2440588.00245,
             epsilon = 0.00001
         )
     }
+
+    #[test]
+    fn test_equation_of_time() {
+        // Early November, the apparent solar time is about 16 minutes ahead.
+        assert_relative_eq!(
+            super::equation_of_time(f64::to_radians(300.), f64::to_radians(221.)) * 1440.,
+            16.4,
+            epsilon = 0.1
+        )
+    }
 }
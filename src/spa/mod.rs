@@ -0,0 +1,351 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+mod terms;
+
+use core::f64::consts::PI;
+
+use chrono::{DateTime, Utc};
+
+use crate::Coordinates;
+use crate::julian::{J2000, JulianDay, centuries_since_j2000, datetime_to_julian};
+use crate::math::{asin, atan2, cos, rem_euclid, sin, tan};
+use crate::model::{Ephemeris, SolarModel};
+use crate::solar_equation::{SolarPosition, azimuth, elevation};
+
+use self::terms::{
+    B0, B1, L0, L1, L2, L3, L4, L5, NUTATION_ARGUMENTS, NUTATION_COEFFICIENTS, R0, R1, R2, R3, R4,
+};
+
+/// Number of seconds in a day.
+const SECONDS_IN_A_DAY: f64 = 86400.;
+
+/// Equatorial radius of the Earth, in meters.
+const EARTH_RADIUS: f64 = 6378140.;
+
+/// Ratio between the polar and the equatorial radius of the Earth.
+const EARTH_FLATTENING: f64 = 0.99664719;
+
+/// The Solar Position Algorithm of the National Renewable Energy Laboratory, see
+/// <https://midcdmz.nrel.gov/spa/>.
+///
+/// This implements the full algorithm described by Reda and Andreas, with the periodic terms of
+/// the Earth orbit, the nutation, the aberration and the parallax of the sun, which is accurate to
+/// ±0.0003° between the years -2000 and 6000.
+///
+/// The accuracy of the algorithm depends on the difference ΔT between the terrestrial time and
+/// the universal time, which can't be predicted precisely: it was observed to be about 69 seconds
+/// in 2024, while the default [`DeltaT`] extrapolates about 74 seconds. Use observed values of
+/// [`DeltaT`] for the best accuracy.
+///
+/// [`DeltaT`]: crate::DeltaT
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use sunrise::{Coordinates, SolarModel, Spa};
+///
+/// let coord = Coordinates::new(39.742476, -105.1786).unwrap();
+/// let time = Utc.with_ymd_and_hms(2003, 10, 17, 19, 30, 30).unwrap();
+///
+/// let position = Spa::new(67.).with_elevation(1830.14).sun_position(coord, time);
+/// assert!((position.azimuth.to_degrees() - 194.34024).abs() < 0.0003);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Spa {
+    delta_t: f64,
+    elevation: f64,
+}
+
+/// Position of the sun relative to the center of the Earth.
+pub(crate) struct Geocentric {
+    /// Apparent ecliptic longitude, in radians.
+    pub(crate) apparent_longitude: f64,
+    right_ascension: f64,
+    declination: f64,
+    radius_vector: f64,
+    sidereal_time: f64,
+    equation_of_time: f64,
+}
+
+impl Spa {
+    /// Initialize given the difference ΔT between the terrestrial time and the universal time, in
+    /// seconds.
+    pub fn new(delta_t: f64) -> Self {
+        Self {
+            delta_t,
+            elevation: 0.,
+        }
+    }
+
+    /// Specify the elevation (in meters) of the observer above the sea level, which is used to
+    /// correct the parallax of the sun. This defaults to 0 if not specified.
+    pub fn with_elevation(mut self, elevation: f64) -> Self {
+        self.elevation = elevation;
+        self
+    }
+
+    /// Get the difference ΔT between the terrestrial time and the universal time, in seconds.
+    pub fn delta_t(&self) -> f64 {
+        self.delta_t
+    }
+
+    /// Calculates the geocentric position of the sun at a given Julian day.
+    pub(crate) fn geocentric(&self, day: f64) -> Geocentric {
+        let century = centuries_since_j2000(day);
+        let ephemeris_century = centuries_since_j2000(day + self.delta_t / SECONDS_IN_A_DAY);
+        let millennium = ephemeris_century / 10.;
+
+        // Heliocentric position of the Earth.
+        let longitude = series(&[&L0, &L1, &L2, &L3, &L4, &L5], millennium);
+        let latitude = series(&[&B0, &B1], millennium);
+        let radius_vector = series(&[&R0, &R1, &R2, &R3, &R4], millennium);
+
+        // Geocentric position of the sun, corrected for the nutation and the aberration.
+        let (nutation_longitude, nutation_obliquity) = nutation(ephemeris_century);
+        let obliquity = mean_obliquity(millennium) + nutation_obliquity;
+        let aberration = -f64::to_radians(20.4898 / 3600.) / radius_vector;
+        let apparent_longitude = longitude + PI + nutation_longitude + aberration;
+        let latitude = -latitude;
+
+        let right_ascension = rem_euclid(
+            atan2(
+                sin(apparent_longitude) * cos(obliquity) - tan(latitude) * sin(obliquity),
+                cos(apparent_longitude),
+            ),
+            2. * PI,
+        );
+        let declination = asin(
+            sin(latitude) * cos(obliquity)
+                + cos(latitude) * sin(obliquity) * sin(apparent_longitude),
+        );
+
+        let mean_sidereal_time = f64::to_radians(
+            280.46061837
+                + 360.98564736629 * (day - J2000)
+                + century * century * (0.000387933 - century / 38710000.),
+        );
+        let sidereal_time = mean_sidereal_time + nutation_longitude * cos(obliquity);
+
+        let mean_longitude = f64::to_radians(
+            280.4664567
+                + millennium
+                    * (360007.6982779
+                        + millennium
+                            * (0.03032028
+                                + millennium
+                                    * (1. / 49931.
+                                        - millennium * (1. / 15300. + millennium / 2000000.)))),
+        );
+        let equation_of_time = rem_euclid(
+            mean_longitude - f64::to_radians(0.0057183) - right_ascension
+                + nutation_longitude * cos(obliquity)
+                + PI,
+            2. * PI,
+        ) - PI;
+
+        Geocentric {
+            apparent_longitude,
+            right_ascension,
+            declination,
+            radius_vector,
+            sidereal_time,
+            equation_of_time: equation_of_time / (2. * PI),
+        }
+    }
+}
+
+impl SolarModel for Spa {
+    fn ephemeris(&self, day: JulianDay) -> Ephemeris {
+        let geocentric = self.geocentric(day.value());
+
+        Ephemeris {
+            declination: geocentric.declination,
+            equation_of_time: geocentric.equation_of_time,
+            radius_vector: geocentric.radius_vector,
+        }
+    }
+
+    fn sun_position(&self, coord: Coordinates, time: DateTime<Utc>) -> SolarPosition {
+        let geocentric = self.geocentric(datetime_to_julian(time));
+        let latitude = coord.lat().to_radians();
+        let hour_angle =
+            geocentric.sidereal_time + coord.lon().to_radians() - geocentric.right_ascension;
+
+        // Parallax of the sun seen from the surface of the Earth.
+        let parallax = f64::to_radians(8.794 / 3600.) / geocentric.radius_vector;
+        let reduced_latitude = atan2(EARTH_FLATTENING * sin(latitude), cos(latitude));
+        let x = cos(reduced_latitude) + self.elevation / EARTH_RADIUS * cos(latitude);
+        let y = EARTH_FLATTENING * sin(reduced_latitude)
+            + self.elevation / EARTH_RADIUS * sin(latitude);
+
+        let denominator = cos(geocentric.declination) - x * sin(parallax) * cos(hour_angle);
+        let parallax_ascension = atan2(-x * sin(parallax) * sin(hour_angle), denominator);
+        let declination = atan2(
+            (sin(geocentric.declination) - y * sin(parallax)) * cos(parallax_ascension),
+            denominator,
+        );
+        let hour_angle = rem_euclid(hour_angle - parallax_ascension + PI, 2. * PI) - PI;
+
+        SolarPosition {
+            elevation: elevation(coord.lat(), declination, hour_angle),
+            azimuth: azimuth(coord.lat(), declination, hour_angle),
+            hour_angle,
+            declination,
+        }
+    }
+}
+
+/// Calculates a quantity from its periodic terms, as a polynomial of the Julian ephemeris
+/// millennium.
+fn series(terms: &[&[[f64; 3]]], millennium: f64) -> f64 {
+    terms.iter().rev().fold(0., |sum, terms| {
+        let value: f64 = terms
+            .iter()
+            .map(|[amplitude, phase, frequency]| amplitude * cos(phase + frequency * millennium))
+            .sum();
+        sum * millennium + value
+    }) / 1e8
+}
+
+/// Calculates the nutation in longitude and in obliquity, in radians.
+fn nutation(century: f64) -> (f64, f64) {
+    let polynomial = |a: f64, b: f64, c: f64, d: f64| {
+        f64::to_radians(a + century * (b + century * (c + century * d)))
+    };
+    let arguments = [
+        polynomial(297.85036, 445267.111480, -0.0019142, 1. / 189474.),
+        polynomial(357.52772, 35999.050340, -0.0001603, -1. / 300000.),
+        polynomial(134.96298, 477198.867398, 0.0086972, 1. / 56250.),
+        polynomial(93.27191, 483202.017538, -0.0036825, 1. / 327270.),
+        polynomial(125.04452, -1934.136261, 0.0020708, 1. / 450000.),
+    ];
+
+    let (longitude, obliquity) = NUTATION_ARGUMENTS
+        .iter()
+        .zip(NUTATION_COEFFICIENTS.iter())
+        .fold(
+            (0., 0.),
+            |(longitude, obliquity), (multipliers, [a, b, c, d])| {
+                let argument: f64 = multipliers
+                    .iter()
+                    .zip(arguments.iter())
+                    .map(|(multiplier, argument)| multiplier * argument)
+                    .sum();
+                (
+                    longitude + (a + b * century) * sin(argument),
+                    obliquity + (c + d * century) * cos(argument),
+                )
+            },
+        );
+
+    (
+        f64::to_radians(longitude / 36000000.),
+        f64::to_radians(obliquity / 36000000.),
+    )
+}
+
+/// Calculates the mean obliquity of the ecliptic, in radians.
+fn mean_obliquity(millennium: f64) -> f64 {
+    const COEFFICIENTS: [f64; 11] = [
+        84381.448, -4680.93, -1.55, 1999.25, -51.38, -249.67, -39.05, 7.12, 27.87, 5.79, 2.45,
+    ];
+    let u = millennium / 10.;
+    let arcseconds = COEFFICIENTS.iter().rev().fold(0., |sum, c| sum * u + c);
+    f64::to_radians(arcseconds / 3600.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Refraction;
+    use approx::assert_relative_eq;
+    use chrono::TimeZone;
+
+    // Example of the NREL report: October 17, 2003 at 12:30:30 in Golden, Colorado.
+    fn example() -> (Coordinates, DateTime<Utc>, Spa) {
+        (
+            Coordinates::new(39.742476, -105.1786).unwrap(),
+            Utc.with_ymd_and_hms(2003, 10, 17, 19, 30, 30).unwrap(),
+            Spa::new(67.).with_elevation(1830.14),
+        )
+    }
+
+    #[test]
+    fn test_geocentric() {
+        let (_, time, spa) = example();
+        let geocentric = spa.geocentric(datetime_to_julian(time));
+        assert_relative_eq!(geocentric.radius_vector, 0.9965422974, epsilon = 1e-9);
+        assert_relative_eq!(
+            geocentric.right_ascension.to_degrees(),
+            202.22741,
+            epsilon = 1e-5
+        );
+        assert_relative_eq!(
+            geocentric.declination.to_degrees(),
+            -9.31434,
+            epsilon = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_equation_of_time() {
+        let (_, time, spa) = example();
+        let ephemeris = spa.ephemeris(JulianDay::from(time));
+        assert_relative_eq!(
+            ephemeris.equation_of_time * 24. * 60.,
+            14.641503,
+            epsilon = 1e-4
+        );
+    }
+
+    #[test]
+    fn test_nutation() {
+        let (longitude, obliquity) = nutation(0.037927819);
+        assert_relative_eq!(longitude.to_degrees(), -0.00399840, epsilon = 1e-8);
+        assert_relative_eq!(obliquity.to_degrees(), 0.00166657, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_position() {
+        let (coord, time, spa) = example();
+        let position = spa.sun_position(coord, time);
+        assert_relative_eq!(position.hour_angle.to_degrees(), 11.10629, epsilon = 1e-4);
+        assert_relative_eq!(position.declination.to_degrees(), -9.316179, epsilon = 1e-5);
+        assert_relative_eq!(position.elevation.to_degrees(), 39.872046, epsilon = 3e-4);
+
+        // The report also applies the refraction to the elevation.
+        let refraction = Refraction::Atmospheric {
+            pressure: 820.,
+            temperature: 11.,
+        };
+        assert_relative_eq!(
+            refraction
+                .apparent_elevation(position.elevation)
+                .to_degrees(),
+            39.888378,
+            epsilon = 3e-4
+        );
+        assert_relative_eq!(position.azimuth.to_degrees(), 194.34024, epsilon = 3e-4);
+    }
+}
@@ -0,0 +1,92 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use core::slice;
+
+use chrono::{DateTime, Utc};
+
+use crate::event::{DawnType, Occurrence, SolarEvent};
+
+/// The standard transitions of a day, in chronological order.
+const EVENTS: [SolarEvent; 9] = [
+    SolarEvent::Dawn(DawnType::Astronomical),
+    SolarEvent::Dawn(DawnType::Nautical),
+    SolarEvent::Dawn(DawnType::Civil),
+    SolarEvent::Sunrise,
+    SolarEvent::Noon,
+    SolarEvent::Sunset,
+    SolarEvent::Dusk(DawnType::Civil),
+    SolarEvent::Dusk(DawnType::Nautical),
+    SolarEvent::Dusk(DawnType::Astronomical),
+];
+
+/// An event of a [`Timeline`] and its occurrence.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct TimelineEntry {
+    /// The solar event.
+    pub event: SolarEvent,
+    /// When the event happens, or why it does not.
+    pub occurrence: Occurrence,
+}
+
+/// All the standard transitions of a solar day, see [`SolarDay::timeline`].
+///
+/// Entries are in chronological order: astronomical, nautical and civil dawn, sunrise, noon,
+/// sunset, and civil, nautical and astronomical dusk.
+///
+/// [`SolarDay::timeline`]: crate::SolarDay::timeline
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Timeline {
+    entries: [TimelineEntry; EVENTS.len()],
+}
+
+impl Timeline {
+    pub(crate) fn new(occurrence: impl Fn(SolarEvent) -> Occurrence) -> Self {
+        Self {
+            entries: EVENTS.map(|event| TimelineEntry {
+                event,
+                occurrence: occurrence(event),
+            }),
+        }
+    }
+
+    /// Iterate over all the entries, including the events which do not happen.
+    pub fn iter(&self) -> slice::Iter<'_, TimelineEntry> {
+        self.entries.iter()
+    }
+
+    /// Iterate over the events which happen during the day along with their time.
+    pub fn occurring(&self) -> impl Iterator<Item = (SolarEvent, DateTime<Utc>)> + '_ {
+        self.entries
+            .iter()
+            .filter_map(|entry| Some((entry.event, entry.occurrence.occurs()?)))
+    }
+}
+
+impl<'a> IntoIterator for &'a Timeline {
+    type Item = &'a TimelineEntry;
+    type IntoIter = slice::Iter<'a, TimelineEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
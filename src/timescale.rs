@@ -0,0 +1,414 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use chrono::{DateTime, Datelike, NaiveDateTime, TimeDelta, Timelike, Utc};
+
+use crate::julian::{datetime_to_julian, modified_julian_day};
+
+/// Difference between the terrestrial time and the international atomic time, in seconds.
+const TT_MINUS_TAI: f64 = 32.184;
+
+/// Largest difference between the universal time and the UTC, which leap seconds keep within
+/// this bound, in seconds.
+const MAX_UT1_MINUS_UTC: f64 = 0.9;
+
+/// Number of iterations when converting from another time scale, the offsets between time scales
+/// vary slowly enough for this to converge.
+const ITERATIONS: usize = 2;
+
+/// Leap seconds introduced since 1972: year and month from which they apply, and the resulting
+/// difference between the international atomic time and the UTC, in seconds.
+const LEAP_SECONDS: [(i32, u32, i32); 28] = [
+    (1972, 1, 10),
+    (1972, 7, 11),
+    (1973, 1, 12),
+    (1974, 1, 13),
+    (1975, 1, 14),
+    (1976, 1, 15),
+    (1977, 1, 16),
+    (1978, 1, 17),
+    (1979, 1, 18),
+    (1980, 1, 19),
+    (1981, 7, 20),
+    (1982, 7, 21),
+    (1983, 7, 22),
+    (1985, 7, 23),
+    (1988, 1, 24),
+    (1990, 1, 25),
+    (1991, 1, 26),
+    (1992, 7, 27),
+    (1993, 7, 28),
+    (1994, 7, 29),
+    (1996, 1, 30),
+    (1997, 7, 31),
+    (1999, 1, 32),
+    (2006, 1, 33),
+    (2009, 1, 34),
+    (2012, 7, 35),
+    (2015, 7, 36),
+    (2017, 1, 37),
+];
+
+/// A time scale in which an instant can be expressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeScale {
+    /// Coordinated universal time, the civil time scale followed by `chrono`.
+    Utc,
+    /// Universal time, which follows the rotation of the Earth.
+    Ut1,
+    /// International atomic time.
+    Tai,
+    /// Terrestrial time, the uniform time scale in which the orbit of the Earth is computed.
+    Tt,
+}
+
+/// Source of the difference ΔT between the terrestrial time and the universal time, which
+/// depends on the irregular rotation of the Earth.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum DeltaT<'a> {
+    /// Polynomial expressions fitted by Espenak and Meeus on historical observations and
+    /// predictions, see <https://eclipse.gsfc.nasa.gov/SEhelp/deltatpoly2004.html>.
+    #[default]
+    EspenakMeeus,
+    /// A fixed value, in seconds.
+    Constant(f64),
+    /// Observed values of ΔT, as pairs of a decimal year and a value in seconds sorted by year.
+    ///
+    /// Values are interpolated linearly and [`DeltaT::EspenakMeeus`] is used outside of the
+    /// table.
+    Observed(&'a [(f64, f64)]),
+    /// Observed values of UT1 - UTC, as published by the IERS, as pairs of a modified Julian day
+    /// and a value in seconds sorted by day.
+    ///
+    /// Values are interpolated linearly and [`DeltaT::EspenakMeeus`] is used outside of the
+    /// table or before 1972.
+    Ut1MinusUtc(&'a [(f64, f64)]),
+}
+
+impl DeltaT<'_> {
+    /// Get the difference between the terrestrial time and the universal time at a given
+    /// instant, in seconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use sunrise::DeltaT;
+    ///
+    /// let delta_t = DeltaT::EspenakMeeus.at(Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap());
+    /// assert!((delta_t - 63.8).abs() < 0.1);
+    /// ```
+    pub fn at(&self, time: DateTime<Utc>) -> f64 {
+        match *self {
+            DeltaT::EspenakMeeus => espenak_meeus(decimal_year(time)),
+            DeltaT::Constant(delta_t) => delta_t,
+            DeltaT::Observed(table) => interpolate(table, decimal_year(time))
+                .unwrap_or_else(|| espenak_meeus(decimal_year(time))),
+            DeltaT::Ut1MinusUtc(table) => {
+                let modified_julian_day = modified_julian_day(datetime_to_julian(time));
+                match (leap_seconds(time), interpolate(table, modified_julian_day)) {
+                    (Some(leap_seconds), Some(ut1_minus_utc)) => {
+                        TT_MINUS_TAI + f64::from(leap_seconds) - ut1_minus_utc
+                    }
+                    _ => espenak_meeus(decimal_year(time)),
+                }
+            }
+        }
+    }
+
+    /// Get the difference between a time scale and the UTC at a given instant.
+    ///
+    /// Before 1972, when leap seconds were introduced, the UTC is assumed to follow the
+    /// universal time. Afterwards, the universal time is kept within 0.9 second of the UTC, even
+    /// when ΔT is extrapolated further away from the leap seconds.
+    pub fn offset(&self, time: DateTime<Utc>, scale: TimeScale) -> TimeDelta {
+        let tt = match leap_seconds(time) {
+            Some(leap_seconds) => f64::from(leap_seconds) + TT_MINUS_TAI,
+            None => self.at(time),
+        };
+        let seconds = match scale {
+            TimeScale::Utc => 0.,
+            TimeScale::Ut1 => (tt - self.at(time)).clamp(-MAX_UT1_MINUS_UTC, MAX_UT1_MINUS_UTC),
+            TimeScale::Tai => tt - TT_MINUS_TAI,
+            TimeScale::Tt => tt,
+        };
+        TimeDelta::nanoseconds((seconds * 1e9) as i64)
+    }
+
+    /// Express an instant in a given time scale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use sunrise::{DeltaT, TimeScale};
+    ///
+    /// let time = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    /// let tt = DeltaT::EspenakMeeus.to_scale(time, TimeScale::Tt);
+    /// assert_eq!((tt - time.naive_utc()).num_milliseconds(), 69184);
+    /// ```
+    pub fn to_scale(&self, time: DateTime<Utc>, scale: TimeScale) -> NaiveDateTime {
+        time.naive_utc() + self.offset(time, scale)
+    }
+
+    /// Get the instant at which a given time scale has a given value.
+    ///
+    /// This is the inverse of [`DeltaT::to_scale`].
+    pub fn from_scale(&self, time: NaiveDateTime, scale: TimeScale) -> DateTime<Utc> {
+        let mut result = time.and_utc();
+
+        for _ in 0..ITERATIONS {
+            result = time.and_utc() - self.offset(result, scale);
+        }
+
+        result
+    }
+
+    /// Read a table of [`DeltaT::Observed`] or [`DeltaT::Ut1MinusUtc`] values from a file.
+    ///
+    /// Each line must start with two numbers separated by whitespace, further columns are
+    /// ignored, as well as empty lines and lines starting with `#`.
+    #[cfg(feature = "std")]
+    pub fn read_table(
+        path: impl AsRef<std::path::Path>,
+    ) -> std::io::Result<std::vec::Vec<(f64, f64)>> {
+        use std::io::{BufRead, BufReader, Error, ErrorKind};
+
+        let file = std::fs::File::open(path)?;
+        let mut table = std::vec::Vec::new();
+
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut columns = line.split_whitespace().map(str::parse::<f64>);
+            match (columns.next(), columns.next()) {
+                (Some(Ok(x)), Some(Ok(y))) => table.push((x, y)),
+                _ => return Err(Error::new(ErrorKind::InvalidData, line)),
+            }
+        }
+
+        Ok(table)
+    }
+}
+
+/// Get the number of leap seconds at a given instant, as the difference between the
+/// international atomic time and the UTC in seconds.
+///
+/// Returns `None` before 1972, when the UTC did not use leap seconds.
+pub fn leap_seconds(time: DateTime<Utc>) -> Option<i32> {
+    LEAP_SECONDS
+        .iter()
+        .rev()
+        .find(|&&(year, month, _)| (time.year(), time.month()) >= (year, month))
+        .map(|&(_, _, leap_seconds)| leap_seconds)
+}
+
+/// Calculates the year with its elapsed fraction.
+fn decimal_year(time: DateTime<Utc>) -> f64 {
+    let days_in_year = if time.date_naive().leap_year() {
+        366.
+    } else {
+        365.
+    };
+    let elapsed = f64::from(time.ordinal0()) + f64::from(time.num_seconds_from_midnight()) / 86400.;
+    f64::from(time.year()) + elapsed / days_in_year
+}
+
+/// Calculates ΔT in seconds with the polynomial expressions of Espenak and Meeus.
+fn espenak_meeus(year: f64) -> f64 {
+    let long_term = |year: f64| {
+        let u = (year - 1820.) / 100.;
+        -20. + 32. * u * u
+    };
+    let polynomial =
+        |t: f64, coefficients: &[f64]| coefficients.iter().rev().fold(0., |sum, c| sum * t + c);
+
+    match year {
+        ..-500. => long_term(year),
+        ..500. => polynomial(
+            year / 100.,
+            &[
+                10583.6,
+                -1014.41,
+                33.78311,
+                -5.952053,
+                -0.1798452,
+                0.022174192,
+                0.0090316521,
+            ],
+        ),
+        ..1600. => polynomial(
+            (year - 1000.) / 100.,
+            &[
+                1574.2,
+                -556.01,
+                71.23472,
+                0.319781,
+                -0.8503463,
+                -0.005050998,
+                0.0083572073,
+            ],
+        ),
+        ..1700. => polynomial(year - 1600., &[120., -0.9808, -0.01532, 1. / 7129.]),
+        ..1800. => polynomial(
+            year - 1700.,
+            &[8.83, 0.1603, -0.0059285, 0.00013336, -1. / 1174000.],
+        ),
+        ..1860. => polynomial(
+            year - 1800.,
+            &[
+                13.72,
+                -0.332447,
+                0.0068612,
+                0.0041116,
+                -0.00037436,
+                0.0000121272,
+                -0.0000001699,
+                0.000000000875,
+            ],
+        ),
+        ..1900. => polynomial(
+            year - 1860.,
+            &[
+                7.62,
+                0.5737,
+                -0.251754,
+                0.01680668,
+                -0.0004473624,
+                1. / 233174.,
+            ],
+        ),
+        ..1920. => polynomial(
+            year - 1900.,
+            &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197],
+        ),
+        ..1941. => polynomial(year - 1920., &[21.20, 0.84493, -0.076100, 0.0020936]),
+        ..1961. => polynomial(year - 1950., &[29.07, 0.407, -1. / 233., 1. / 2547.]),
+        ..1986. => polynomial(year - 1975., &[45.45, 1.067, -1. / 260., -1. / 718.]),
+        ..2005. => polynomial(
+            year - 2000.,
+            &[
+                63.86,
+                0.3345,
+                -0.060374,
+                0.0017275,
+                0.000651814,
+                0.00002373599,
+            ],
+        ),
+        ..2050. => polynomial(year - 2000., &[62.92, 0.32217, 0.005589]),
+        ..2150. => long_term(year) - 0.5628 * (2150. - year),
+        _ => long_term(year),
+    }
+}
+
+/// Interpolates linearly in a table sorted by its first column.
+///
+/// Returns `None` outside of the range of the table.
+fn interpolate(table: &[(f64, f64)], x: f64) -> Option<f64> {
+    let index = table.partition_point(|&(key, _)| key <= x);
+    match (table.get(index.wrapping_sub(1)), table.get(index)) {
+        (Some(&(x0, y0)), Some(&(x1, y1))) => Some(y0 + (y1 - y0) * (x - x0) / (x1 - x0)),
+        (Some(&(x0, y0)), None) if x == x0 => Some(y0),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_leap_seconds() {
+        let time = |year, month| Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).unwrap();
+        assert_eq!(leap_seconds(time(1971, 12)), None);
+        assert_eq!(leap_seconds(time(1972, 1)), Some(10));
+        assert_eq!(leap_seconds(time(2016, 12)), Some(36));
+        assert_eq!(leap_seconds(time(2017, 1)), Some(37));
+        assert_eq!(leap_seconds(time(2024, 6)), Some(37));
+    }
+
+    #[test]
+    fn test_espenak_meeus() {
+        assert_relative_eq!(espenak_meeus(1900.), -2.79, epsilon = 0.01);
+        assert_relative_eq!(espenak_meeus(1950.), 29.07, epsilon = 0.01);
+        assert_relative_eq!(espenak_meeus(2000.), 63.86, epsilon = 0.01);
+        // Historical values, see the table of the NASA eclipse website.
+        assert_relative_eq!(espenak_meeus(1000.), 1574., epsilon = 1.);
+        assert_relative_eq!(espenak_meeus(-500.), 17203.7, epsilon = 1.);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let table = [(0., 1.), (1., 3.), (3., 4.)];
+        assert_eq!(interpolate(&table, -1.), None);
+        assert_eq!(interpolate(&table, 0.5), Some(2.));
+        assert_eq!(interpolate(&table, 2.), Some(3.5));
+        assert_eq!(interpolate(&table, 3.), Some(4.));
+        assert_eq!(interpolate(&table, 4.), None);
+        assert_eq!(interpolate(&[], 0.), None);
+    }
+
+    #[test]
+    fn test_ut1_minus_utc() {
+        let time = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let table = [(58849., -0.1772), (58850., -0.1775)];
+        let delta_t = DeltaT::Ut1MinusUtc(&table);
+        assert_relative_eq!(delta_t.at(time), 69.3612, epsilon = 1e-4);
+        assert_eq!(
+            delta_t.offset(time, TimeScale::Ut1).num_milliseconds(),
+            -177
+        );
+    }
+
+    #[test]
+    fn test_ut1_current() {
+        // The polynomial of Espenak and Meeus overestimates ΔT by about 5 s in 2024.
+        let time = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        assert!(DeltaT::EspenakMeeus.at(time) - 69.184 > 4.);
+        for delta_t in [DeltaT::EspenakMeeus, DeltaT::Ut1MinusUtc(&[(0., 0.)])] {
+            let offset = delta_t.offset(time, TimeScale::Ut1);
+            assert!(offset.abs() <= TimeDelta::milliseconds(900), "{offset}");
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let time = Utc.with_ymd_and_hms(1900, 6, 1, 12, 0, 0).unwrap();
+        for scale in [
+            TimeScale::Utc,
+            TimeScale::Ut1,
+            TimeScale::Tai,
+            TimeScale::Tt,
+        ] {
+            let converted = DeltaT::EspenakMeeus.to_scale(time, scale);
+            assert_eq!(DeltaT::EspenakMeeus.from_scale(converted, scale), time);
+        }
+    }
+}
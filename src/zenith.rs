@@ -0,0 +1,180 @@
+// The MIT License (MIT)
+//
+// Copyright (c) 2026 Nathan Osman
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+
+use core::f64::consts::FRAC_PI_2;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::event::Culmination;
+use crate::model::SolarModel;
+use crate::solar_equation::SolarDay;
+
+/// Iterate over the days of a year at which the elevation of the sun at solar noon reaches a
+/// given value (**in radians**), in chronological order, along with the culmination of that day
+/// in the solar days built by `solar_day`.
+///
+/// Since the noon elevation changes by less than half a degree per day, the reported
+/// day is the one at which it is the closest to the requested value.
+///
+/// The noon elevation reaches a given value up to four times per year, when the sun culminates on
+/// either side of the zenith. Returns nothing if the elevation is out of range
+/// (`abs(elevation) > pi/2`).
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use sunrise::{Coordinates, SolarDay, noon_elevation_dates};
+///
+/// // Paris
+/// let coord = Coordinates::new(48.8566, 2.3522).unwrap();
+/// let solar_day = |date| SolarDay::new(coord, date);
+///
+/// // The sun culminates at 30° in February and in October.
+/// for (date, noon) in noon_elevation_dates(solar_day, f64::to_radians(30.), 2024) {
+///     println!("{date}: {noon:?}");
+/// }
+/// ```
+pub fn noon_elevation_dates<M: SolarModel>(
+    solar_day: impl Fn(NaiveDate) -> SolarDay<M>,
+    elevation: f64,
+    year: i32,
+) -> impl Iterator<Item = (NaiveDate, Culmination)> {
+    // The sun culminates at this elevation when it is this far from the zenith, northward or
+    // southward.
+    let zenith_angle = FRAC_PI_2 - elevation;
+    let in_range = (-FRAC_PI_2..=FRAC_PI_2).contains(&elevation);
+    let targets = [
+        Some(zenith_angle),
+        Some(-zenith_angle).filter(|_| zenith_angle != 0.),
+    ];
+
+    // Days are compared with their neighbours, including the ones of the adjacent years.
+    let first = NaiveDate::from_ymd_opt(year, 1, 1)
+        .and_then(|date| date.pred_opt())
+        .filter(|_| in_range);
+    let dates = first
+        .into_iter()
+        .flat_map(|first| first.iter_days())
+        .take_while(move |date| date.year() <= year || date.ordinal() == 1);
+
+    let mut previous: Option<(NaiveDate, f64)> = None;
+
+    dates.flat_map(move |date| {
+        let day = solar_day(date);
+        let angle = day.noon_zenith_angle();
+        let crossings = previous.map(|(previous_date, previous_angle)| {
+            targets.map(|target| {
+                let target = target?;
+                let (before, after) = (previous_angle - target, angle - target);
+                if before != 0. && before.signum() == after.signum() {
+                    return None;
+                }
+
+                let closest = if after.abs() < before.abs() {
+                    date
+                } else {
+                    previous_date
+                };
+                (closest.year() == year).then(|| (closest, solar_day(closest).noon()))
+            })
+        });
+        previous = Some((date, angle));
+
+        crossings.into_iter().flatten().flatten()
+    })
+}
+
+/// Iterate over the days of a year at which the sun passes through the zenith at solar noon, in
+/// chronological order, along with the culmination of that day.
+///
+/// This happens on up to two days per year between the tropics, when the declination of the sun
+/// is equal to the latitude, and objects cast no shadow at noon.
+///
+/// # Example
+///
+/// ```
+/// use chrono::Datelike;
+/// use sunrise::{Coordinates, SolarDay, zenith_passages};
+///
+/// // Honolulu
+/// let coord = Coordinates::new(21.3069, -157.8583).unwrap();
+/// let passages: Vec<_> = zenith_passages(|date| SolarDay::new(coord, date), 2024).collect();
+///
+/// assert_eq!(passages.len(), 2);
+/// assert_eq!(passages[0].0.month(), 5);
+/// assert_eq!(passages[1].0.month(), 7);
+/// ```
+pub fn zenith_passages<M: SolarModel>(
+    solar_day: impl Fn(NaiveDate) -> SolarDay<M>,
+    year: i32,
+) -> impl Iterator<Item = (NaiveDate, Culmination)> {
+    noon_elevation_dates(solar_day, FRAC_PI_2, year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Coordinates;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_zenith_passages() {
+        // Honolulu
+        let coord = Coordinates::new(21.3069, -157.8583).unwrap();
+        let solar_day = |date| SolarDay::new(coord, date);
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+
+        let mut passages = zenith_passages(solar_day, 2024);
+        let (first, noon) = passages.next().unwrap();
+        assert!(first >= date(5, 25) && first <= date(5, 29));
+        assert_relative_eq!(noon.elevation.to_degrees(), 90., epsilon = 0.25);
+        let (second, noon) = passages.next().unwrap();
+        assert!(second >= date(7, 14) && second <= date(7, 18));
+        assert_relative_eq!(noon.elevation.to_degrees(), 90., epsilon = 0.25);
+        assert_eq!(passages.next(), None);
+    }
+
+    #[test]
+    fn test_outside_tropics() {
+        let coord = Coordinates::new(48.8566, 2.3522).unwrap();
+        let solar_day = |date| SolarDay::new(coord, date);
+        assert_eq!(zenith_passages(solar_day, 2024).count(), 0);
+    }
+
+    #[test]
+    fn test_noon_elevation_dates() {
+        let coord = Coordinates::new(48.8566, 2.3522).unwrap();
+        let solar_day = |date| SolarDay::new(coord, date);
+
+        let mut dates = noon_elevation_dates(solar_day, f64::to_radians(30.), 2024);
+        for month in [2, 10] {
+            let (date, noon) = dates.next().unwrap();
+            assert_eq!(date.month(), month);
+            assert_relative_eq!(noon.elevation.to_degrees(), 30., epsilon = 0.25);
+        }
+        assert_eq!(dates.next(), None);
+
+        // Out of range.
+        assert_eq!(noon_elevation_dates(solar_day, 2., 2024).count(), 0);
+    }
+}
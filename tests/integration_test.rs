@@ -22,8 +22,14 @@
 
 use core::f64::consts::PI;
 
-use chrono::{DateTime, NaiveDate};
-use sunrise::{Coordinates, DawnType, SolarDay, SolarEvent};
+use approx::assert_relative_eq;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeDelta, Timelike};
+use sunrise::{
+    AnnualSummary, Coordinates, DawnType, HorizonProfile, Limb, LocalSolarDay, Noaa, Occurrence,
+    Precision, Refraction, Rounding, Season, SolarDay, SolarEvent, SolarModel, Spa,
+    day_length_change, next_event, noon_elevation_dates, perihelion, polar_periods, previous_event,
+    solar_longitude_time, solar_terms, sun_position, sun_semi_diameter, zenith_passages,
+};
 
 #[allow(deprecated)]
 use sunrise::sunrise_sunset;
@@ -33,6 +39,7 @@ fn solar_day(year: i32) -> SolarDay {
         Coordinates::new(0., 0.).unwrap(),
         NaiveDate::from_ymd_opt(year, 1, 1).unwrap(),
     )
+    .with_rounding(Rounding::Truncate)
 }
 
 #[test]
@@ -52,6 +59,44 @@ fn test_sunrise() {
 }
 
 #[test]
+fn test_rounding() {
+    let sunrise = solar_day(1970)
+        .with_rounding(Rounding::None)
+        .event_time(SolarEvent::Sunrise)
+        .unwrap();
+    assert_eq!(sunrise.timestamp(), 21594);
+    assert_ne!(sunrise.timestamp_subsec_nanos(), 0);
+
+    assert_eq!(
+        solar_day(1970)
+            .with_rounding(Rounding::NearestSecond)
+            .event_time(SolarEvent::Sunset)
+            .unwrap(),
+        DateTime::parse_from_rfc3339("1970-01-01T18:07:09Z").unwrap()
+    );
+
+    assert_eq!(
+        solar_day(1970)
+            .with_rounding(Rounding::NearestMinute)
+            .event_time(SolarEvent::Sunrise)
+            .unwrap(),
+        DateTime::parse_from_rfc3339("1970-01-01T06:00:00Z").unwrap()
+    );
+
+    // Beyond the range of timestamps in nanoseconds.
+    let sunrise = SolarDay::new(
+        Coordinates::new(45., 0.).unwrap(),
+        NaiveDate::from_ymd_opt(2300, 6, 1).unwrap(),
+    )
+    .with_rounding(Rounding::NearestMinute)
+    .event_time(SolarEvent::Sunrise)
+    .unwrap();
+    assert_eq!(sunrise.second(), 0);
+    assert_eq!(sunrise.nanosecond(), 0);
+}
+
+#[test]
+#[allow(deprecated)]
 fn test_altitude() {
     assert_eq!(
         solar_day(1970)
@@ -130,7 +175,7 @@ fn test_elevation() {
                 morning: true
             })
             .unwrap(),
-        DateTime::parse_from_rfc3339("2023-01-01T02:42:24Z").unwrap()
+        DateTime::parse_from_rfc3339("2023-01-01T09:23:57Z").unwrap()
     );
 
     assert_eq!(
@@ -140,11 +185,27 @@ fn test_elevation() {
                 morning: false
             })
             .unwrap(),
-        DateTime::parse_from_rfc3339("2023-01-01T21:24:02Z").unwrap()
+        DateTime::parse_from_rfc3339("2023-01-01T14:42:29Z").unwrap()
+    );
+
+    assert_eq!(
+        solar_day(2023).event_time(SolarEvent::elevation_degrees(45., false).unwrap()),
+        solar_day(2023).event_time(SolarEvent::Elevation {
+            elevation: PI / 4.0,
+            morning: false
+        })
+    );
+
+    assert_eq!(
+        solar_day(2023)
+            .event_time(SolarEvent::depression(PI / 4.0, true).unwrap())
+            .unwrap(),
+        DateTime::parse_from_rfc3339("2023-01-01T02:42:24Z").unwrap()
     );
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_order() {
     let sd = {
         SolarDay::new(
@@ -160,11 +221,11 @@ fn test_order() {
         sd.event_time(SolarEvent::Dawn(DawnType::Civil)),
         sd.event_time(SolarEvent::Sunrise),
         sd.event_time(SolarEvent::Elevation {
-            elevation: -0.1,
+            elevation: 0.1,
             morning: true,
         }),
         sd.event_time(SolarEvent::Elevation {
-            elevation: -0.1,
+            elevation: 0.1,
             morning: false,
         }),
         sd.event_time(SolarEvent::Sunset),
@@ -192,3 +253,718 @@ fn test_polar_day() {
     assert_eq!(antarctic_polar_night.event_time(SolarEvent::Sunrise), None);
     assert_eq!(antarctic_polar_night.event_time(SolarEvent::Sunset), None);
 }
+
+#[test]
+fn test_precise() {
+    let coord = Coordinates::new(65., 25.).unwrap();
+    let date = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+    let sunrise_angle = -f64::to_radians(5.) / 6.;
+
+    let fast = SolarDay::new(coord, date)
+        .event_time(SolarEvent::Sunrise)
+        .unwrap();
+    let precise = SolarDay::new(coord, date)
+        .with_precision(Precision::Precise)
+        .event_time(SolarEvent::Sunrise)
+        .unwrap();
+
+    let fast_error = (sun_position(coord, fast).elevation - sunrise_angle).abs();
+    let precise_error = (sun_position(coord, precise).elevation - sunrise_angle).abs();
+    assert!(precise_error < fast_error);
+    assert!(precise_error < f64::to_radians(0.01));
+}
+
+#[test]
+fn test_polar_occurrence() {
+    let arctic_polar_day = SolarDay::new(
+        Coordinates::new(85., 0.).unwrap(),
+        NaiveDate::from_ymd_opt(1970, 8, 1).unwrap(),
+    );
+    assert_eq!(
+        arctic_polar_day.event_occurrence(SolarEvent::Sunset),
+        Occurrence::AlwaysAbove
+    );
+
+    let antarctic_polar_night = SolarDay::new(
+        Coordinates::new(-85., 0.).unwrap(),
+        NaiveDate::from_ymd_opt(1970, 8, 1).unwrap(),
+    );
+    assert_eq!(
+        antarctic_polar_night.event_occurrence(SolarEvent::Sunrise),
+        Occurrence::AlwaysBelow
+    );
+    assert_eq!(
+        antarctic_polar_night.event_occurrence(SolarEvent::Dawn(DawnType::Civil)),
+        Occurrence::AlwaysBelow
+    );
+}
+
+#[test]
+fn test_culminations() {
+    let sd = solar_day(1970);
+    let noon = sd.noon();
+    let midnight = sd.midnight();
+
+    assert_eq!(sd.event_time(SolarEvent::Noon), Some(noon.time));
+    assert_eq!(sd.event_time(SolarEvent::Midnight), Some(midnight.time));
+    assert_eq!(
+        noon.time,
+        DateTime::parse_from_rfc3339("1970-01-01T12:03:31Z").unwrap()
+    );
+    assert_eq!(
+        midnight.time,
+        DateTime::parse_from_rfc3339("1970-01-01T00:03:31Z").unwrap()
+    );
+    assert!((noon.elevation - f64::to_radians(90. - 22.97753)).abs() < 1e-4);
+    assert!((midnight.elevation + f64::to_radians(90. - 22.97753)).abs() < 1e-4);
+
+    // The sun stays below the horizon at noon during the polar night, and above it at midnight
+    // during the midnight sun.
+    let date = NaiveDate::from_ymd_opt(1970, 12, 21).unwrap();
+    let polar_night = SolarDay::new(Coordinates::new(80., 0.).unwrap(), date).noon();
+    assert!(polar_night.elevation < 0.);
+    let midnight_sun = SolarDay::new(Coordinates::new(-80., 0.).unwrap(), date).midnight();
+    assert!(midnight_sun.elevation > 0.);
+}
+
+#[test]
+fn test_timeline() {
+    let sd = SolarDay::new(
+        Coordinates::new(60., 10.).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(),
+    );
+    let timeline = sd.timeline();
+
+    for entry in &timeline {
+        assert_eq!(entry.occurrence, sd.event_occurrence(entry.event));
+    }
+
+    // Around the summer solstice in Oslo, the sky never gets darker than nautical twilight.
+    let skipped: Vec<_> = timeline
+        .iter()
+        .filter(|entry| entry.occurrence.occurs().is_none())
+        .collect();
+    assert_eq!(skipped.len(), 4);
+    assert!(
+        skipped
+            .iter()
+            .all(|entry| entry.occurrence == Occurrence::AlwaysAbove)
+    );
+
+    let times: Vec<_> = timeline.occurring().map(|(_, time)| time).collect();
+    assert_eq!(times.len(), 5);
+    assert!(times.is_sorted());
+}
+
+#[test]
+fn test_next_previous_event() {
+    let coord = Coordinates::new(0., 0.).unwrap();
+    let day = |date| SolarDay::new(coord, date).with_rounding(Rounding::Truncate);
+    let sunrise = DateTime::parse_from_rfc3339("1970-01-01T05:59:54Z")
+        .unwrap()
+        .to_utc();
+    let sunset = DateTime::parse_from_rfc3339("1970-01-01T18:07:08Z")
+        .unwrap()
+        .to_utc();
+
+    assert_eq!(
+        next_event(day, SolarEvent::Sunset, sunrise, 1),
+        Some(sunset)
+    );
+    assert_eq!(
+        previous_event(day, SolarEvent::Sunrise, sunset, 1),
+        Some(sunrise)
+    );
+
+    let next_sunrise = next_event(day, SolarEvent::Sunrise, sunrise, 1).unwrap();
+    assert_eq!(
+        next_sunrise.date_naive(),
+        sunrise.date_naive().succ_opt().unwrap()
+    );
+    assert_eq!(
+        previous_event(day, SolarEvent::Sunrise, next_sunrise, 1),
+        Some(sunrise)
+    );
+
+    // Without extra days, the search still reaches the events of the next and previous dates.
+    let coord = Coordinates::new(43.6532, -79.3832).unwrap();
+    let day = |date| SolarDay::new(coord, date);
+    let evening = DateTime::parse_from_rfc3339("2016-01-01T23:00:00Z")
+        .unwrap()
+        .to_utc();
+    let date = NaiveDate::from_ymd_opt(2016, 1, 2).unwrap();
+    let next_sunrise = day(date).event_time(SolarEvent::Sunrise);
+    assert!(next_sunrise.is_some());
+    assert_eq!(
+        next_event(day, SolarEvent::Sunrise, evening, 0),
+        next_sunrise
+    );
+    let morning = DateTime::parse_from_rfc3339("2016-01-02T01:00:00Z")
+        .unwrap()
+        .to_utc();
+    let previous_sunset = day(date.pred_opt().unwrap()).event_time(SolarEvent::Sunset);
+    assert_eq!(
+        previous_event(day, SolarEvent::Sunset, morning, 0),
+        previous_sunset
+    );
+}
+
+#[test]
+fn test_next_event_date_line() {
+    // Near the date line, the sunset of a solar day happens on the next UTC date.
+    let coord = Coordinates::new(10., -179.5).unwrap();
+    let day = |date| SolarDay::new(coord, date);
+    let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let sunset = day(date).event_time(SolarEvent::Sunset).unwrap();
+    assert_eq!(sunset.date_naive(), date.succ_opt().unwrap());
+
+    let morning = date
+        .succ_opt()
+        .unwrap()
+        .and_hms_opt(1, 0, 0)
+        .unwrap()
+        .and_utc();
+    assert_eq!(
+        next_event(day, SolarEvent::Sunset, morning, 1),
+        Some(sunset)
+    );
+    assert_eq!(
+        previous_event(
+            day,
+            SolarEvent::Sunset,
+            sunset + chrono::TimeDelta::hours(1),
+            1
+        ),
+        Some(sunset)
+    );
+}
+
+#[test]
+fn test_next_event_polar() {
+    let coord = Coordinates::new(80., 0.).unwrap();
+    let day = |date| SolarDay::new(coord, date);
+    let start = DateTime::parse_from_rfc3339("2024-12-01T00:00:00Z")
+        .unwrap()
+        .to_utc();
+
+    assert_eq!(next_event(day, SolarEvent::Sunrise, start, 30), None);
+
+    let sunrise = next_event(day, SolarEvent::Sunrise, start, 120).unwrap();
+    assert_eq!((sunrise.year(), sunrise.month()), (2025, 2));
+
+    let sunset = previous_event(day, SolarEvent::Sunset, start, 120).unwrap();
+    assert_eq!((sunset.year(), sunset.month()), (2024, 10));
+}
+
+#[test]
+fn test_local_date_line() {
+    // Kiritimati is at UTC+14 while its longitude is close to -157.5, so the events of the solar
+    // day with the same date fall on the next local date.
+    let coord = Coordinates::new(1.87, -157.4).unwrap();
+    let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let tz = FixedOffset::east_opt(14 * 3600).unwrap();
+
+    let sunrise = SolarDay::new(coord, date)
+        .event_time(SolarEvent::Sunrise)
+        .unwrap();
+    assert_ne!(sunrise.with_timezone(&tz).date_naive(), date);
+
+    let local = LocalSolarDay::new(coord, date, tz);
+    for event in [SolarEvent::Sunrise, SolarEvent::Noon, SolarEvent::Sunset] {
+        assert_eq!(local.event_time(event).unwrap().date_naive(), date);
+    }
+}
+
+#[test]
+fn test_local_dst() {
+    // Daylight saving time starts on March 31, 2024 in Oslo.
+    let coord = Coordinates::new(59.9139, 10.7522).unwrap();
+    let date = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+    let local = LocalSolarDay::new(coord, date, chrono_tz::Europe::Oslo);
+
+    let sunrise = local.event_time(SolarEvent::Sunrise).unwrap();
+    let sunset = local.event_time(SolarEvent::Sunset).unwrap();
+    assert_eq!(sunrise.date_naive(), date);
+    assert_eq!(sunset.date_naive(), date);
+    assert_eq!(sunrise.offset().to_string(), "CEST");
+    assert_eq!(
+        sunrise.to_utc(),
+        SolarDay::new(coord, date)
+            .event_time(SolarEvent::Sunrise)
+            .unwrap()
+    );
+
+    // The midnight sun does not set.
+    let summer = LocalSolarDay::new(
+        Coordinates::new(78.22, 15.65).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(),
+        chrono_tz::Arctic::Longyearbyen,
+    );
+    assert_eq!(summer.event_time(SolarEvent::Sunset), None);
+}
+
+#[test]
+fn test_refraction() {
+    let sd = solar_day(1970);
+    assert_eq!(
+        sd.with_refraction(Refraction::Standard),
+        sd,
+        "standard refraction is the default"
+    );
+
+    let standard = sd.event_time(SolarEvent::Sunrise).unwrap();
+    let geometric = sd
+        .with_refraction(Refraction::None)
+        .event_time(SolarEvent::Sunrise)
+        .unwrap();
+    let cold = sd
+        .with_refraction(Refraction::Atmospheric {
+            pressure: 1040.,
+            temperature: -40.,
+        })
+        .event_time(SolarEvent::Sunrise)
+        .unwrap();
+    assert!(cold < standard);
+    assert!(standard < geometric);
+
+    // Twilight is defined by the geometric elevation of the sun.
+    let dawn = SolarEvent::Dawn(DawnType::Civil);
+    assert_eq!(
+        sd.with_refraction(Refraction::None).event_time(dawn),
+        sd.event_time(dawn)
+    );
+
+    // Near the horizon, refraction makes the sun reach an apparent elevation earlier.
+    let elevation = SolarEvent::elevation_degrees(1., true).unwrap();
+    assert!(
+        sd.with_refraction(Refraction::Atmospheric {
+            pressure: 1010.,
+            temperature: 10.
+        })
+        .event_time(elevation)
+            < sd.with_refraction(Refraction::None).event_time(elevation)
+    );
+    assert!(sd.event_time(elevation) < sd.with_refraction(Refraction::None).event_time(elevation));
+}
+
+#[test]
+fn test_limb() {
+    let sd = solar_day(2024).with_rounding(Rounding::None);
+    let upper = sd.with_limb(Limb::Upper);
+    let center = sd.with_limb(Limb::Center);
+    let lower = sd.with_limb(Limb::Lower);
+
+    let sunrises = [
+        upper.event_time(SolarEvent::Sunrise),
+        center.event_time(SolarEvent::Sunrise),
+        lower.event_time(SolarEvent::Sunrise),
+    ];
+    assert!(sunrises.is_sorted());
+
+    let sunsets = [
+        lower.event_time(SolarEvent::Sunset),
+        center.event_time(SolarEvent::Sunset),
+        upper.event_time(SolarEvent::Sunset),
+    ];
+    assert!(sunsets.is_sorted());
+
+    // The sun looks bigger in January, close to the perihelion.
+    assert!(upper.event_time(SolarEvent::Sunrise) < sd.event_time(SolarEvent::Sunrise));
+    assert!(sun_semi_diameter(perihelion(2024).unwrap()) > f64::to_radians(16. / 60.));
+}
+
+#[test]
+fn test_noaa() {
+    let sd = solar_day(2024);
+    let noaa = sd.with_model(Noaa);
+
+    for event in [SolarEvent::Sunrise, SolarEvent::Noon, SolarEvent::Sunset] {
+        let delta = noaa.event_time(event).unwrap() - sd.event_time(event).unwrap();
+        assert!(delta.num_seconds().abs() < 60);
+        // The options are kept when changing the model.
+        assert_eq!(noaa.event_time(event).unwrap().timestamp_subsec_nanos(), 0);
+    }
+}
+
+#[test]
+fn test_spa() {
+    // Example of the NREL report: October 17, 2003 in Golden, Colorado.
+    let coord = Coordinates::new(39.742476, -105.1786).unwrap();
+    let spa = Spa::new(67.);
+    let sd = SolarDay::new(coord, NaiveDate::from_ymd_opt(2003, 10, 17).unwrap())
+        .with_model(spa)
+        .with_precision(Precision::Precise);
+
+    // The report gives a solar transit at 11:46:04 local time.
+    let noon = sd.event_time(SolarEvent::Noon).unwrap();
+    assert!((noon.timestamp() - 1066416364).abs() <= 5);
+
+    // Events ignore the parallax of the sun, which is up to 9 arcseconds.
+    for event in [SolarEvent::Sunrise, SolarEvent::Sunset] {
+        let position = spa.sun_position(coord, sd.event_time(event).unwrap());
+        assert_relative_eq!(
+            position.elevation,
+            -f64::to_radians(50. / 60.),
+            epsilon = f64::to_radians(0.005)
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_delta_t_table() {
+    use sunrise::{DeltaT, TimeScale};
+
+    let path =
+        std::env::temp_dir().join(format!("sunrise_delta_t_table_{}.txt", std::process::id()));
+    std::fs::write(
+        &path,
+        "# MJD UT1-UTC\n58849 -0.1772\n\n58850 -0.1775 0.0001\n",
+    )
+    .unwrap();
+    let table = DeltaT::read_table(&path);
+    std::fs::write(&path, "58849 not-a-number\n").unwrap();
+    let invalid = DeltaT::read_table(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    let table = table.unwrap();
+    assert_eq!(table, [(58849., -0.1772), (58850., -0.1775)]);
+    assert!(invalid.is_err());
+
+    let time = DateTime::from_timestamp(1577880000, 0).unwrap();
+    let ut1 = DeltaT::Ut1MinusUtc(&table).to_scale(time, TimeScale::Ut1);
+    assert_eq!((ut1 - time.naive_utc()).num_milliseconds(), -177);
+}
+
+#[test]
+fn test_observer_height() {
+    let sd = solar_day(2024).with_rounding(Rounding::None);
+    let tower = sd.with_observer_height(100.);
+    let valley = sd.with_observer_height(-100.);
+
+    assert!(tower.event_time(SolarEvent::Sunrise) < sd.event_time(SolarEvent::Sunrise));
+    assert!(tower.event_time(SolarEvent::Sunset) > sd.event_time(SolarEvent::Sunset));
+    assert!(valley.event_time(SolarEvent::Sunrise) > sd.event_time(SolarEvent::Sunrise));
+
+    // Twilights are defined relative to the astronomical horizon.
+    let dawn = SolarEvent::Dawn(DawnType::Civil);
+    assert_eq!(tower.event_time(dawn), sd.event_time(dawn));
+
+    // The horizon is seen about 18' below the astronomical one from 100 m, and the sun is
+    // refracted about 4' more on it, which takes over a minute and a half at the equator.
+    let delta = sd.event_time(SolarEvent::Sunrise).unwrap()
+        - tower.event_time(SolarEvent::Sunrise).unwrap();
+    assert!(delta.num_seconds() > 85 && delta.num_seconds() < 105);
+}
+
+#[test]
+fn test_aircraft_height() {
+    let sd = solar_day(2024).with_rounding(Rounding::None);
+    let sunrise = |sd: SolarDay| sd.event_time(SolarEvent::Sunrise).unwrap();
+    let advance = |sd: SolarDay| sunrise(sd) - sunrise(sd.with_observer_height(10000.));
+
+    // The horizon dips by about 3° from 10 km, and the sun is refracted more on that horizon
+    // than on the astronomical one, which brings sunrise more than a minute earlier still.
+    let geometric = advance(sd.with_refraction(Refraction::None));
+    assert!(geometric.num_minutes() >= 12 && geometric.num_minutes() < 14);
+    assert!((advance(sd) - geometric).num_seconds() > 60);
+}
+
+#[test]
+fn test_station_elevation() {
+    let sd = solar_day(2024).with_rounding(Rounding::None);
+    let mountain = sd.with_elevation(3000.);
+
+    // There is less refraction at high elevation, which delays sunrise.
+    assert!(mountain.event_time(SolarEvent::Sunrise) > sd.event_time(SolarEvent::Sunrise));
+    assert!(mountain.event_time(SolarEvent::Sunset) < sd.event_time(SolarEvent::Sunset));
+    assert_eq!(
+        mountain.event_time(SolarEvent::Noon),
+        sd.event_time(SolarEvent::Noon)
+    );
+
+    // Sunrise gets later as the elevation rises, even by a few meters.
+    let sunrises = [0., 1., 10., 100., 3000.]
+        .map(|elevation| sd.with_elevation(elevation).event_time(SolarEvent::Sunrise));
+    assert!(sunrises.windows(2).all(|pair| pair[0] < pair[1]));
+
+    // Above the atmosphere, there is no refraction at all.
+    assert_eq!(
+        sd.with_elevation(50000.).event_time(SolarEvent::Sunrise),
+        sd.with_refraction(Refraction::None)
+            .event_time(SolarEvent::Sunrise)
+    );
+}
+
+#[test]
+fn test_flat_horizon() {
+    let points = [(0., 0.)];
+    let flat = HorizonProfile::new(&points).unwrap();
+    let sd = solar_day(2024)
+        .with_rounding(Rounding::None)
+        .with_refraction(Refraction::None)
+        .with_precision(Precision::Precise);
+
+    let delta =
+        sd.visible_sunrise(&flat).occurs().unwrap() - sd.event_time(SolarEvent::Sunrise).unwrap();
+    assert!(delta.num_milliseconds().abs() < 100);
+    let delta =
+        sd.visible_sunset(&flat).occurs().unwrap() - sd.event_time(SolarEvent::Sunset).unwrap();
+    assert!(delta.num_milliseconds().abs() < 100);
+}
+
+#[test]
+fn test_mountains() {
+    let sd = solar_day(2024);
+
+    // Mountains rising 10° on the east side of the equator delay the sunrise by about 40 minutes.
+    let points = [(0., 0.), (60., 10.), (150., 10.), (180., 0.)];
+    let valley = HorizonProfile::new(&points).unwrap();
+    let sunrise = sd.visible_sunrise(&valley).occurs().unwrap();
+    let delay = sunrise - sd.event_time(SolarEvent::Sunrise).unwrap();
+    assert!(delay.num_minutes() > 35 && delay.num_minutes() < 50);
+    assert_eq!(sunrise.timestamp_subsec_nanos(), 0);
+
+    // Behind a wall the sun never appears.
+    let points = [(0., 80.)];
+    let wall = HorizonProfile::new(&points).unwrap();
+    assert_eq!(sd.visible_sunrise(&wall), Occurrence::AlwaysBelow);
+    // Under the midnight sun, the sun never disappears behind low hills.
+    let points = [(0., 2.)];
+    let hills = HorizonProfile::new(&points).unwrap();
+    let midnight_sun = SolarDay::new(
+        Coordinates::new(80., 0.).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(),
+    );
+    assert_eq!(midnight_sun.visible_sunset(&hills), Occurrence::AlwaysAbove);
+
+    // At the end of the midnight sun, the sun sets behind the hills late in the evening and
+    // stays hidden for the rest of the day, so it does not rise.
+    let points = [(0., 3.5)];
+    let hills = HorizonProfile::new(&points).unwrap();
+    let last_day = SolarDay::new(
+        Coordinates::new(80., 0.).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 8, 18).unwrap(),
+    );
+    assert!(last_day.visible_sunset(&hills).occurs().is_some());
+    assert_eq!(last_day.visible_sunrise(&hills), Occurrence::AlwaysBelow);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_dem() {
+    use sunrise::{Dem, DemGeometry, HorizonOptions, SampleFormat};
+
+    // A plain at the equator with a 1500 m high ridge 3 km to the east.
+    let mut content =
+        String::from("ncols 101\nnrows 101\nxllcorner -0.045\nyllcorner -0.045\ncellsize 0.0009\n");
+    for _ in 0..101 {
+        for column in 0..101 {
+            content.push_str(if column == 83 { "1500 " } else { "0 " });
+        }
+        content.push('\n');
+    }
+    let path = std::env::temp_dir().join(format!("sunrise_dem_{}.asc", std::process::id()));
+    std::fs::write(&path, content).unwrap();
+    let dem = Dem::read_esri_ascii(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let coord = Coordinates::new(0., 0.).unwrap();
+    let options = HorizonOptions::default().with_radius(5000.);
+    let points = dem.horizon(coord, &options).unwrap();
+    let horizon = HorizonProfile::new(&points).unwrap();
+
+    // The ridge rises over 20° above the observer, hiding the sun for an hour and a half.
+    let sd = solar_day(2024);
+    let delay = sd.visible_sunrise(&horizon).occurs().unwrap()
+        - sd.event_time(SolarEvent::Sunrise).unwrap();
+    assert!(delay.num_minutes() > 80 && delay.num_minutes() < 100);
+    assert!(sd.visible_sunset(&horizon).occurs() > sd.event_time(SolarEvent::Sunset));
+
+    // The same grid as a raw heightmap.
+    let bytes: Vec<u8> = (0..101 * 101)
+        .flat_map(|index| if index % 101 == 83 { 1500i16 } else { 0 }.to_be_bytes())
+        .collect();
+    let path = std::env::temp_dir().join(format!("sunrise_dem_{}.hgt", std::process::id()));
+    std::fs::write(&path, bytes).unwrap();
+    let geometry = dem.geometry();
+    let heightmap = Dem::read_heightmap(&path, geometry, SampleFormat::I16BigEndian).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(heightmap, dem);
+
+    let geometry = DemGeometry {
+        rows: 100,
+        ..geometry
+    };
+    assert!(Dem::new(geometry, vec![0.; 101 * 101]).is_err());
+}
+
+#[test]
+fn test_event_azimuth() {
+    let sd = solar_day(2024);
+
+    // The sun is south of the equator in January.
+    let sunrise = sd.event_azimuth(SolarEvent::Sunrise).unwrap();
+    let sunset = sd.event_azimuth(SolarEvent::Sunset).unwrap();
+    assert_relative_eq!(sunrise, 113., epsilon = 0.5);
+    assert_relative_eq!(sunset, 360. - sunrise, epsilon = 0.1);
+    assert_relative_eq!(
+        sd.event_azimuth(SolarEvent::Noon).unwrap(),
+        180.,
+        epsilon = 1e-6
+    );
+
+    let precise = sd.with_precision(Precision::Precise);
+    assert_relative_eq!(
+        precise.event_azimuth(SolarEvent::Sunrise).unwrap(),
+        sunrise,
+        epsilon = 0.05
+    );
+
+    let polar_night = SolarDay::new(
+        Coordinates::new(80., 0.).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 12, 21).unwrap(),
+    );
+    assert_eq!(polar_night.event_azimuth(SolarEvent::Sunrise), None);
+}
+
+#[test]
+fn test_seasons() {
+    let coord = Coordinates::new(0., 0.).unwrap();
+
+    // The sun is on the equator at the equinoxes.
+    for season in [Season::MarchEquinox, Season::SeptemberEquinox] {
+        let time = season.time(2024).unwrap();
+        assert_relative_eq!(
+            Noaa.sun_position(coord, time).declination.to_degrees(),
+            0.,
+            epsilon = 0.01
+        );
+    }
+
+    // And at its northernmost declination at the June solstice.
+    let solstice = Season::JuneSolstice.time(2024).unwrap();
+    let declination = |time| Noaa.sun_position(coord, time).declination;
+    let day = chrono::TimeDelta::days(1);
+    assert!(declination(solstice) > declination(solstice - day));
+    assert!(declination(solstice) > declination(solstice + day));
+
+    // Cross-quarter days are solar terms.
+    let beltane = solar_longitude_time(2024, 45.).unwrap();
+    assert!(solar_terms(2024).any(|time| time == beltane));
+    assert!(solar_terms(2024).all(|time| time.year() == 2024));
+}
+
+#[test]
+fn test_annual_summary() {
+    let coord = Coordinates::new(43.6532, -79.3832).unwrap();
+    let solar_day = |date| SolarDay::new(coord, date);
+    let summary = AnnualSummary::new(solar_day, 2016).unwrap();
+
+    // The earliest sunset happens a couple of weeks before the winter solstice, and the latest
+    // sunrise a couple of weeks after.
+    let earliest_sunset = summary.earliest_sunset.unwrap();
+    assert_eq!(earliest_sunset.month(), 12);
+    assert!(earliest_sunset.day() < 15);
+    let latest_sunrise = summary.latest_sunrise.unwrap();
+    assert_eq!(latest_sunrise.month(), 1);
+
+    // The day length matches the solar day of the longest day.
+    let (date, length) = summary.longest_day;
+    assert_eq!(solar_day(date).day_length(), length);
+    assert_eq!(length.num_hours(), 15);
+
+    // Day length changes the fastest around the equinoxes.
+    let (date, change) = summary.fastest_lengthening;
+    assert_eq!(day_length_change(solar_day, date), Some(change));
+    assert!((3..=4).contains(&date.month()));
+    let (date, _) = summary.fastest_shortening;
+    assert!((9..=10).contains(&date.month()));
+
+    // Half of the year is daylight, plus some more due to refraction.
+    let hours = summary.total_daylight.num_hours();
+    assert!((366 * 12..366 * 13).contains(&hours));
+}
+
+#[test]
+fn test_precise_day_length() {
+    // On the last day of the midnight sun, the sun sets late in the evening but does not rise,
+    // since it was already up at the previous solar midnight.
+    let coord = Coordinates::new(66., 15.).unwrap();
+    let solar_day = |day| {
+        SolarDay::new(coord, NaiveDate::from_ymd_opt(2024, 6, day).unwrap())
+            .with_precision(Precision::Precise)
+    };
+    let last_day = solar_day(30);
+    assert_eq!(
+        last_day.event_occurrence(SolarEvent::Sunrise),
+        Occurrence::AlwaysAbove
+    );
+    assert!(
+        last_day
+            .event_occurrence(SolarEvent::Sunset)
+            .occurs()
+            .is_some()
+    );
+
+    // The day length shrinks smoothly from 24 hours.
+    let lengths = [29, 30].map(|day| solar_day(day).day_length());
+    let next = SolarDay::new(coord, NaiveDate::from_ymd_opt(2024, 7, 1).unwrap())
+        .with_precision(Precision::Precise)
+        .day_length();
+    assert_eq!(lengths[0], TimeDelta::days(1));
+    assert!(lengths[1] < lengths[0] && lengths[1] > next);
+    assert!(lengths[0] - lengths[1] < TimeDelta::hours(1));
+}
+
+#[test]
+fn test_polar_periods() {
+    // Tromsø
+    let coord = Coordinates::new(69.6496, 18.956).unwrap();
+    let solar_day = |date| SolarDay::new(coord, date);
+
+    let midnight_sun = polar_periods(solar_day, SolarEvent::Sunrise, 2024)
+        .find(|period| period.above)
+        .unwrap();
+    assert_eq!(
+        solar_day(midnight_sun.first).event_occurrence(SolarEvent::Sunset),
+        Occurrence::AlwaysAbove
+    );
+    assert!(
+        solar_day(midnight_sun.first.pred_opt().unwrap())
+            .event_time(SolarEvent::Sunset)
+            .is_some()
+    );
+    assert!(
+        solar_day(midnight_sun.last.succ_opt().unwrap())
+            .event_time(SolarEvent::Sunset)
+            .is_some()
+    );
+
+    // Twilight never stops at noon in Tromsø.
+    let mut civil = polar_periods(solar_day, SolarEvent::Dawn(DawnType::Civil), 2024);
+    assert!(civil.all(|period| period.above));
+}
+
+#[test]
+fn test_zenith_passages() {
+    // Singapore, close to the equator: the sun is overhead around the equinoxes.
+    let coord = Coordinates::new(1.3521, 103.8198).unwrap();
+    let solar_day = |date| SolarDay::new(coord, date);
+
+    let mut passages = zenith_passages(solar_day, 2024);
+    for (month, day) in [(3, 23), (9, 19)] {
+        let (date, noon) = passages.next().unwrap();
+        assert_eq!(date.month(), month);
+        assert!(date.day().abs_diff(day) <= 1);
+        assert_eq!(noon.time, solar_day(date).noon().time);
+        assert!(noon.elevation.to_degrees() > 89.8);
+    }
+    assert_eq!(passages.next(), None);
+
+    // At noon, the sun never gets lower than 65° there.
+    let low = noon_elevation_dates(solar_day, f64::to_radians(64.), 2024);
+    assert_eq!(low.count(), 0);
+    let high = noon_elevation_dates(solar_day, f64::to_radians(70.), 2024);
+    assert_eq!(high.count(), 4);
+}